@@ -12,18 +12,17 @@ use schemars::JsonSchema;
 use serde::Deserialize;
 
 use krabdex::types::{GenerationName, GenerationRef, Limit, Offset, PageRequest, PokemonName, PokemonRef};
-use std::sync::Arc;
 
 #[derive(Clone)]
 pub struct KrabdexMcp {
-    client: Arc<krabdex::PokeApiClient>,
+    client: krabdex::PokeApiClient,
     tool_router: ToolRouter<Self>,
 }
 
 impl KrabdexMcp {
     pub fn new(client: krabdex::PokeApiClient) -> Self {
         Self {
-            client: Arc::new(client),
+            client,
             tool_router: Self::tool_router(),
         }
     }
@@ -41,21 +40,15 @@ impl KrabdexMcp {
         // Put the verbose stuff in `data` rather than a massive message string.
         let (code, msg) = match &e {
             krabdex::Error::InvalidArgument { .. } => (ErrorCode::INVALID_PARAMS, "Invalid arguments"),
-            krabdex::Error::Api(api) if api.status == 404 => (ErrorCode::INVALID_REQUEST, "Not found"),
+            _ if e.is_not_found() => (ErrorCode::INVALID_REQUEST, "Not found"),
+            _ if e.is_rate_limited() => (ErrorCode::INTERNAL_ERROR, "Rate limited by upstream API"),
             krabdex::Error::Api(_) => (ErrorCode::INTERNAL_ERROR, "Upstream API error"),
             krabdex::Error::Transport { .. } => (ErrorCode::INTERNAL_ERROR, "Network/transport error"),
             krabdex::Error::Deserialize { .. } => (ErrorCode::INTERNAL_ERROR, "Deserialize error"),
             _ => (ErrorCode::INTERNAL_ERROR, "Unexpected error"),
         };
 
-        Self::mcp_err(
-            code,
-            msg,
-            Some(serde_json::json!({
-                "error": format!("{e}"),
-                "kind": format!("{:?}", e),
-            })),
-        )
+        Self::mcp_err(code, msg, serde_json::to_value(e.report()).ok())
     }
 
     fn json_ok<T: serde::Serialize>(value: &T) -> Result<CallToolResult, McpError> {
@@ -0,0 +1,66 @@
+use url::Url;
+
+use crate::{
+    assets::{sniff::media_type, Asset, AssetKind, AssetStore, StoredAsset},
+    client::PokeApiClient,
+    error::{classify::{classify_http_error, Target}, Error, Result},
+    http::{HttpRequest, Method},
+};
+
+impl PokeApiClient {
+    /// Download a sprite or cry (e.g. `pokemon.cries.latest`) and verify it is media.
+    ///
+    /// `url` must be absolute. The body is checked by its magic bytes (falling
+    /// back to an `image/*` or `audio/*` `Content-Type`), and must match the kind
+    /// implied by the URL's extension when it has one. Requests go through the
+    /// client's middleware, retries and timeouts, but skip the response cache and
+    /// the default headers, which are meant for the API host.
+    pub async fn fetch_asset(&self, url: &str) -> Result<Asset> {
+        let parsed = Url::parse(url)
+            .ok()
+            .filter(|u| matches!(u.scheme(), "http" | "https"))
+            .ok_or_else(|| Error::InvalidArgument {
+                field: "url",
+                reason: "must be an absolute http(s) URL".into(),
+            })?;
+        let expected = AssetKind::from_url(url);
+
+        let mut req = HttpRequest::new(Method::Get, parsed);
+        if let Some(kind) = expected {
+            req.headers.insert("accept", kind.accept());
+        }
+        req.timeout = self.options.timeout;
+
+        let resp = self.send_uncached(req).await?;
+        if !resp.is_success() {
+            let target = Target::new("asset", url);
+            return Err(classify_http_error(resp.status, url.to_string(), &resp, Some(&target)));
+        }
+
+        let header = resp.headers.get("content-type");
+        let (kind, content_type) = media_type(header, &resp.body)
+            .filter(|(kind, _)| expected.is_none_or(|e| e == *kind))
+            .ok_or_else(|| Error::UnexpectedContentType {
+                url: url.to_string(),
+                content_type: header.map(str::to_string),
+            })?;
+
+        Ok(Asset {
+            url: url.to_string(),
+            kind,
+            content_type,
+            bytes: resp.body,
+        })
+    }
+
+    /// Download an asset and write it into a content-addressed `store`.
+    ///
+    /// The file write runs on tokio's blocking pool, off the async worker threads.
+    pub async fn download_asset(&self, url: &str, store: &AssetStore) -> Result<StoredAsset> {
+        let asset = self.fetch_asset(url).await?;
+        let store = store.clone();
+        tokio::task::spawn_blocking(move || store.put(&asset))
+            .await
+            .map_err(|_| Error::Internal("asset store write panicked"))?
+    }
+}
@@ -0,0 +1,45 @@
+use crate::{
+    client::{PokeApiClient, pagination::page_query},
+    error::{Result, classify::Target},
+    models::{common::{NamedApiResource, Page}, form::PokemonForm, pokemon::Pokemon},
+    types::{pagination::PageRequest, identifiers::{PokemonName, PokemonRef}},
+};
+
+impl PokeApiClient {
+    /// Fetch a Pokemon form by numeric id.
+    pub async fn pokemon_form_by_id(&self, id: u32) -> Result<PokemonForm> {
+        self.get_resource(Target::new("pokemon-form", id)).await
+    }
+
+    /// Fetch a Pokemon form by name (e.g. `charizard-mega-x`).
+    ///
+    /// Form names follow the same rules as Pokemon names, so they are passed as `PokemonName`.
+    pub async fn pokemon_form_by_name<'a>(&self, name: impl Into<PokemonName<'a>>) -> Result<PokemonForm> {
+        self.get_resource(Target::new("pokemon-form", name.into().as_str())).await
+    }
+
+    /// Fetch a Pokemon form by id or name (convenience API).
+    pub async fn pokemon_form<'a>(&self, form: impl Into<PokemonRef<'a>>) -> Result<PokemonForm> {
+        match form.into() {
+            PokemonRef::Id(id) => self.pokemon_form_by_id(id).await,
+            PokemonRef::Name(name) => self.pokemon_form_by_name(name).await,
+        }
+    }
+
+    /// Resolve every entry of `pokemon.forms` into a `PokemonForm`, in order.
+    pub async fn pokemon_forms(&self, pokemon: &Pokemon) -> Result<Vec<PokemonForm>> {
+        let mut forms = Vec::with_capacity(pokemon.forms.len());
+        for form in &pokemon.forms {
+            forms.push(self.get_resource(Target::new("pokemon-form", &form.name)).await?);
+        }
+        Ok(forms)
+    }
+
+    /// List Pokemon forms (name+url) with pagination.
+    ///
+    /// GET /pokemon-form?limit=...&offset=...
+    pub async fn pokemon_form_list(&self, page: PageRequest) -> Result<Page<NamedApiResource>> {
+        let q = page_query(page);
+        self.get_json("pokemon-form", Some(q)).await
+    }
+}
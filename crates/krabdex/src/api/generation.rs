@@ -1,6 +1,6 @@
 use crate::{
-    client::{PokeApiClient, pagination::page_query},
-    error::Result,
+    client::{PokeApiClient, fetched::Fetched, pagination::page_query},
+    error::{Result, classify::Target},
     models::{
         common::{NamedApiResource, Page},
         generation::Generation,
@@ -14,24 +14,35 @@ use crate::{
 impl PokeApiClient {
     /// Fetch a Generation by numeric id.
     pub async fn generation_by_id(&self, id: u32) -> Result<Generation> {
-        let path = format!("generation/{id}");
-        self.get_json(&path, None).await
+        self.get_resource(Target::new("generation", id)).await
     }
 
     /// Fetch a Generation by validated name.
-    pub async fn generation_by_name(&self, name: GenerationName<'_>) -> Result<Generation> {
-        let path = format!("generation/{}", name.as_str());
-        self.get_json(&path, None).await
+    ///
+    /// Accepts a `GenerationName` or a `&GenerationNameBuf`.
+    pub async fn generation_by_name<'a>(&self, name: impl Into<GenerationName<'a>>) -> Result<Generation> {
+        self.get_resource(Target::new("generation", name.into().as_str())).await
     }
 
     /// Fetch a Generation by id or name (convenience API).
-    pub async fn generation(&self, gen: GenerationRef<'_>) -> Result<Generation> {
-        match gen {
+    ///
+    /// Accepts a `GenerationRef`, a `u32` id, a `GenerationName` or a `&GenerationNameBuf`.
+    pub async fn generation<'a>(&self, gen: impl Into<GenerationRef<'a>>) -> Result<Generation> {
+        match gen.into() {
             GenerationRef::Id(id) => self.generation_by_id(id).await,
             GenerationRef::Name(name) => self.generation_by_name(name).await,
         }
     }
 
+    /// Fetch a Generation together with the raw upstream response.
+    pub async fn generation_fetched<'a>(&self, gen: impl Into<GenerationRef<'a>>) -> Result<Fetched<Generation>> {
+        let target = match gen.into() {
+            GenerationRef::Id(id) => Target::new("generation", id),
+            GenerationRef::Name(name) => Target::new("generation", name.as_str()),
+        };
+        self.fetch_resource(target).await
+    }
+
     /// List generations (name+url) with pagination.
     ///
     /// GET /generation?limit=...&offset=...
@@ -1,2 +1,8 @@
 pub mod pokemon;
-pub mod generation;
\ No newline at end of file
+pub mod generation;
+pub mod form;
+pub mod species;
+pub mod raw;
+pub mod assets;
+pub mod names;
+pub mod resources;
@@ -0,0 +1,38 @@
+use std::sync::Arc;
+
+use tracing::Instrument;
+
+use crate::{
+    client::PokeApiClient,
+    error::{Error, Result},
+    index::{NameIndex, NameMatch},
+};
+
+impl PokeApiClient {
+    /// Every name of a resource kind (`"pokemon"`, `"generation"`, `"ability"`, ...).
+    ///
+    /// The first call walks the list endpoint page by page (concurrent first
+    /// calls share that load); the index is then cached on the client and
+    /// shared by its clones. A failed load is retried on the next call.
+    pub async fn name_index(&self, resource: &'static str) -> Result<Arc<NameIndex>> {
+        let cell = self.name_index_cell(resource);
+        let index = cell
+            .get_or_try_init(|| async {
+                let span = tracing::debug_span!("krabdex.name_index", resource);
+                let names = self.load_names(resource).instrument(span).await?;
+                Ok::<_, Error>(Arc::new(NameIndex::new(names)))
+            })
+            .await?;
+        Ok(Arc::clone(index))
+    }
+
+    /// Search Pokemon names (prefix, substring and typo-tolerant), best match first.
+    pub async fn search_pokemon(&self, query: &str, limit: usize) -> Result<Vec<NameMatch>> {
+        Ok(self.name_index("pokemon").await?.search(query, limit))
+    }
+
+    /// Search generation names, best match first.
+    pub async fn search_generations(&self, query: &str, limit: usize) -> Result<Vec<NameMatch>> {
+        Ok(self.name_index("generation").await?.search(query, limit))
+    }
+}
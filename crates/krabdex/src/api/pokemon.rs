@@ -1,6 +1,6 @@
 use crate::{
-    client::{PokeApiClient, pagination::page_query},
-    error::Result,
+    client::{PokeApiClient, fetched::Fetched, pagination::page_query},
+    error::{Result, classify::Target},
     models::{common::{NamedApiResource, Page}, pokemon::Pokemon},
     types::{pagination::PageRequest, identifiers::{PokemonName, PokemonRef}},
 };
@@ -8,24 +8,35 @@ use crate::{
 impl PokeApiClient {
     /// Fetch a Pokemon by numeric id.
     pub async fn pokemon_by_id(&self, id: u32) -> Result<Pokemon> {
-        let path = format!("pokemon/{id}");
-        self.get_json(&path, None).await
+        self.get_resource(Target::new("pokemon", id)).await
     }
 
     /// Fetch a Pokemon by validated name (lowercase, hyphenated).
-    pub async fn pokemon_by_name(&self, name: PokemonName<'_>) -> Result<Pokemon> {
-        let path = format!("pokemon/{}", name.as_str());
-        self.get_json(&path, None).await
+    ///
+    /// Accepts a `PokemonName` or a `&PokemonNameBuf`.
+    pub async fn pokemon_by_name<'a>(&self, name: impl Into<PokemonName<'a>>) -> Result<Pokemon> {
+        self.get_resource(Target::new("pokemon", name.into().as_str())).await
     }
 
     /// Fetch a Pokemon by id or name (convenience API).
-    pub async fn pokemon(&self, pokemon: PokemonRef<'_>) -> Result<Pokemon> {
-        match pokemon {
+    ///
+    /// Accepts a `PokemonRef`, a `u32` id, a `PokemonName` or a `&PokemonNameBuf`.
+    pub async fn pokemon<'a>(&self, pokemon: impl Into<PokemonRef<'a>>) -> Result<Pokemon> {
+        match pokemon.into() {
             PokemonRef::Id(id) => self.pokemon_by_id(id).await,
             PokemonRef::Name(name) => self.pokemon_by_name(name).await,
         }
     }
 
+    /// Fetch a Pokemon together with the raw upstream response.
+    pub async fn pokemon_fetched<'a>(&self, pokemon: impl Into<PokemonRef<'a>>) -> Result<Fetched<Pokemon>> {
+        let target = match pokemon.into() {
+            PokemonRef::Id(id) => Target::new("pokemon", id),
+            PokemonRef::Name(name) => Target::new("pokemon", name.as_str()),
+        };
+        self.fetch_resource(target).await
+    }
+
     /// List Pokemon resources (name+url) with pagination.
     ///
     /// This maps to GET /pokemon?limit=...&offset=...
@@ -0,0 +1,57 @@
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+use crate::{
+    client::{PokeApiClient, fetched::Fetched},
+    error::{Error, Result},
+    http::Query,
+};
+
+impl PokeApiClient {
+    /// Fetch any endpoint as untyped JSON.
+    ///
+    /// `path` is relative to the API prefix (e.g. `"berry/1"` or `"ability"`), and the
+    /// request goes through the same headers, cache, retries, middleware and error
+    /// classification as the typed methods.
+    pub async fn get_raw(&self, path: &str, query: Option<Query>) -> Result<Value> {
+        self.get_typed(path, query).await
+    }
+
+    /// Fetch any endpoint and deserialize it into a caller-provided type.
+    ///
+    /// Useful for endpoints krabdex does not model yet:
+    /// ```no_run
+    /// # async fn run(client: krabdex::PokeApiClient) -> krabdex::Result<()> {
+    /// #[derive(serde::Deserialize)]
+    /// struct Berry { id: u32, name: String }
+    ///
+    /// let berry: Berry = client.get_typed("berry/1", None).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_typed<T: DeserializeOwned>(&self, path: &str, query: Option<Query>) -> Result<T> {
+        validate_relative_path(path)?;
+        self.get_json(path, query).await
+    }
+
+    /// Fetch any endpoint, keeping the raw response alongside the typed value.
+    pub async fn get_fetched<T: DeserializeOwned>(
+        &self,
+        path: &str,
+        query: Option<Query>,
+    ) -> Result<Fetched<T>> {
+        validate_relative_path(path)?;
+        self.fetch_json(path, query).await
+    }
+}
+
+/// Reject absolute URLs so requests (and default headers) never leave the configured base.
+fn validate_relative_path(path: &str) -> Result<()> {
+    if url::Url::parse(path).is_ok() {
+        return Err(Error::InvalidArgument {
+            field: "path",
+            reason: "must be relative to the API prefix, not an absolute URL".into(),
+        });
+    }
+    Ok(())
+}
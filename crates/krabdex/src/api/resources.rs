@@ -0,0 +1,44 @@
+use crate::{
+    client::PokeApiClient,
+    error::{Error, Result},
+    http::url::resource_path,
+    models::common::NamedApiResource,
+    types::identifiers::{GenerationRef, PokemonRef, ResourceId},
+};
+
+impl PokeApiClient {
+    /// Kind and id of a resource URL returned by this client's PokeAPI instance.
+    ///
+    /// The URL must live under the configured `base_url` and `api_prefix`, so
+    /// links from a mirror are accepted only by a client pointed at that mirror.
+    pub fn resource_id(&self, url: &str) -> Result<ResourceId> {
+        let config = &self.inner.config;
+        let (kind, identifier) = resource_path(&config.base_url, &config.api_prefix, url)?;
+        let id = identifier.parse().map_err(|_| Error::InvalidArgument {
+            field: "url",
+            reason: format!("expected a numeric id, got `{identifier}`"),
+        })?;
+        Ok(ResourceId { kind, id })
+    }
+
+    /// Turn a Pokemon link (e.g. from `pokemon_list` results) into a `PokemonRef`.
+    pub fn pokemon_ref(&self, resource: &NamedApiResource) -> Result<PokemonRef<'static>> {
+        self.resource_id_of_kind(resource, "pokemon").map(PokemonRef::Id)
+    }
+
+    /// Turn a generation link into a `GenerationRef`.
+    pub fn generation_ref(&self, resource: &NamedApiResource) -> Result<GenerationRef<'static>> {
+        self.resource_id_of_kind(resource, "generation").map(GenerationRef::Id)
+    }
+
+    fn resource_id_of_kind(&self, resource: &NamedApiResource, kind: &str) -> Result<u32> {
+        let parsed = self.resource_id(&resource.url)?;
+        if parsed.kind != kind {
+            return Err(Error::InvalidArgument {
+                field: "url",
+                reason: format!("expected a {kind} URL, got {}", parsed.kind),
+            });
+        }
+        Ok(parsed.id)
+    }
+}
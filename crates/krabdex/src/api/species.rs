@@ -0,0 +1,49 @@
+use crate::{
+    client::{PokeApiClient, pagination::page_query},
+    error::{Result, classify::Target},
+    models::{common::{NamedApiResource, Page}, pokemon::Pokemon, species::PokemonSpecies},
+    types::{pagination::PageRequest, identifiers::{PokemonName, PokemonRef}},
+};
+
+impl PokeApiClient {
+    /// Fetch a Pokemon species by numeric id.
+    pub async fn pokemon_species_by_id(&self, id: u32) -> Result<PokemonSpecies> {
+        self.get_resource(Target::new("pokemon-species", id)).await
+    }
+
+    /// Fetch a Pokemon species by name (species names follow the Pokemon name rules).
+    pub async fn pokemon_species_by_name<'a>(&self, name: impl Into<PokemonName<'a>>) -> Result<PokemonSpecies> {
+        self.get_resource(Target::new("pokemon-species", name.into().as_str())).await
+    }
+
+    /// Fetch a Pokemon species by id or name (convenience API).
+    pub async fn pokemon_species<'a>(&self, species: impl Into<PokemonRef<'a>>) -> Result<PokemonSpecies> {
+        match species.into() {
+            PokemonRef::Id(id) => self.pokemon_species_by_id(id).await,
+            PokemonRef::Name(name) => self.pokemon_species_by_name(name).await,
+        }
+    }
+
+    /// Every variety of a species (default form, regional forms, megas,
+    /// Gigantamax, ...) fetched as full `Pokemon`, default variety first.
+    pub async fn pokemon_varieties<'a>(&self, species: impl Into<PokemonRef<'a>>) -> Result<Vec<Pokemon>> {
+        let species = self.pokemon_species(species).await?;
+
+        let mut varieties: Vec<_> = species.varieties.iter().collect();
+        varieties.sort_by_key(|v| !v.is_default);
+
+        let mut pokemon = Vec::with_capacity(varieties.len());
+        for variety in varieties {
+            pokemon.push(self.get_resource(Target::new("pokemon", &variety.pokemon.name)).await?);
+        }
+        Ok(pokemon)
+    }
+
+    /// List Pokemon species (name+url) with pagination.
+    ///
+    /// GET /pokemon-species?limit=...&offset=...
+    pub async fn pokemon_species_list(&self, page: PageRequest) -> Result<Page<NamedApiResource>> {
+        let q = page_query(page);
+        self.get_json("pokemon-species", Some(q)).await
+    }
+}
@@ -0,0 +1,65 @@
+use sha2::{Digest, Sha256};
+
+/// Broad category of a downloaded asset.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AssetKind {
+    /// Sprites and artwork (PNG, GIF, SVG, ...).
+    Image,
+    /// Cries (OGG, MP3, WAV).
+    Audio,
+}
+
+impl AssetKind {
+    /// Kind implied by a URL's file extension, if it is a known media extension.
+    pub fn from_url(url: &str) -> Option<Self> {
+        let path = url.split(['?', '#']).next().unwrap_or(url);
+        let ext = path.rsplit_once('.')?.1.to_ascii_lowercase();
+        match ext.as_str() {
+            "png" | "gif" | "jpg" | "jpeg" | "webp" | "svg" => Some(Self::Image),
+            "ogg" | "mp3" | "wav" => Some(Self::Audio),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn accept(&self) -> &'static str {
+        match self {
+            Self::Image => "image/*",
+            Self::Audio => "audio/*",
+        }
+    }
+}
+
+/// A downloaded sprite or cry with its verified content type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Asset {
+    pub url: String,
+    pub kind: AssetKind,
+    /// Media type verified against the body (e.g. `image/png`).
+    pub content_type: String,
+    pub bytes: Vec<u8>,
+}
+
+impl Asset {
+    /// Lowercase hex SHA-256 of the bytes; the asset's address in an `AssetStore`.
+    pub fn sha256(&self) -> String {
+        Sha256::digest(&self.bytes)
+            .iter()
+            .map(|b| format!("{b:02x}"))
+            .collect()
+    }
+
+    /// File extension for the content type (`png`, `ogg`, ...; `bin` if unknown).
+    pub fn extension(&self) -> &'static str {
+        match self.content_type.as_str() {
+            "image/png" => "png",
+            "image/gif" => "gif",
+            "image/jpeg" => "jpg",
+            "image/webp" => "webp",
+            "image/svg+xml" => "svg",
+            "audio/ogg" => "ogg",
+            "audio/mpeg" => "mp3",
+            "audio/wav" => "wav",
+            _ => "bin",
+        }
+    }
+}
@@ -0,0 +1,9 @@
+pub mod asset;
+pub mod store;
+pub(crate) mod sniff;
+
+pub use asset::{Asset, AssetKind};
+pub use store::{AssetStore, StoredAsset};
+
+#[cfg(test)]
+mod tests;
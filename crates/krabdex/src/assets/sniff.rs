@@ -0,0 +1,53 @@
+use crate::assets::AssetKind;
+
+/// Media type of `body`, from its magic bytes.
+///
+/// The `Content-Type` header is not trusted on its own: GitHub's raw host
+/// serves many binaries as `text/plain` or `application/octet-stream`.
+pub(crate) fn sniff(body: &[u8]) -> Option<(AssetKind, &'static str)> {
+    let riff = |tag: &[u8]| body.len() >= 12 && body.starts_with(b"RIFF") && &body[8..12] == tag;
+
+    if body.starts_with(b"\x89PNG\r\n\x1a\n") {
+        Some((AssetKind::Image, "image/png"))
+    } else if body.starts_with(b"GIF87a") || body.starts_with(b"GIF89a") {
+        Some((AssetKind::Image, "image/gif"))
+    } else if body.starts_with(&[0xFF, 0xD8, 0xFF]) {
+        Some((AssetKind::Image, "image/jpeg"))
+    } else if riff(b"WEBP") {
+        Some((AssetKind::Image, "image/webp"))
+    } else if is_svg(body) {
+        Some((AssetKind::Image, "image/svg+xml"))
+    } else if body.starts_with(b"OggS") {
+        Some((AssetKind::Audio, "audio/ogg"))
+    } else if body.starts_with(b"ID3") || body.starts_with(&[0xFF, 0xFB]) {
+        Some((AssetKind::Audio, "audio/mpeg"))
+    } else if riff(b"WAVE") {
+        Some((AssetKind::Audio, "audio/wav"))
+    } else {
+        None
+    }
+}
+
+fn is_svg(body: &[u8]) -> bool {
+    let head = String::from_utf8_lossy(&body[..body.len().min(1024)]);
+    let head = head.trim_start();
+    (head.starts_with("<svg") || head.starts_with("<?xml")) && head.contains("<svg")
+}
+
+/// Verified media type of a downloaded asset, or `None` if it is not media.
+///
+/// Sniffed bytes win over the header; the header is only used for media
+/// formats we cannot sniff, and only when it names an image or audio type.
+pub(crate) fn media_type(header: Option<&str>, body: &[u8]) -> Option<(AssetKind, String)> {
+    if let Some((kind, mime)) = sniff(body) {
+        return Some((kind, mime.to_string()));
+    }
+
+    let mime = header?.split(';').next()?.trim().to_ascii_lowercase();
+    let kind = match mime.split_once('/')?.0 {
+        "image" => AssetKind::Image,
+        "audio" => AssetKind::Audio,
+        _ => return None,
+    };
+    Some((kind, mime))
+}
@@ -0,0 +1,86 @@
+use std::{
+    fs,
+    io::Write,
+    path::{Path, PathBuf},
+};
+
+use tempfile::NamedTempFile;
+
+use crate::{
+    assets::Asset,
+    error::{Error, Result},
+};
+
+/// Content-addressed directory of downloaded assets.
+///
+/// Files live at `<root>/<first two hex chars>/<sha256>.<ext>`, so identical
+/// bytes are stored once no matter how many URLs point at them.
+#[derive(Debug, Clone)]
+pub struct AssetStore {
+    root: PathBuf,
+}
+
+/// Where an asset was stored.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StoredAsset {
+    pub url: String,
+    pub sha256: String,
+    pub content_type: String,
+    pub path: PathBuf,
+    pub size: u64,
+}
+
+impl AssetStore {
+    /// Store rooted at `root` (created on first write).
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// Path an asset with this hash and extension is stored at.
+    pub fn path_for(&self, sha256: &str, extension: &str) -> PathBuf {
+        let shard = sha256.get(..2).unwrap_or(sha256);
+        self.root.join(shard).join(format!("{sha256}.{extension}"))
+    }
+
+    /// Write `asset` into the store, skipping the write if the content is already present.
+    pub fn put(&self, asset: &Asset) -> Result<StoredAsset> {
+        let sha256 = asset.sha256();
+        let path = self.path_for(&sha256, asset.extension());
+
+        if !path.exists() {
+            write_atomic(&path, &asset.bytes)?;
+        }
+
+        Ok(StoredAsset {
+            url: asset.url.clone(),
+            sha256,
+            content_type: asset.content_type.clone(),
+            path,
+            size: asset.bytes.len() as u64,
+        })
+    }
+}
+
+/// Write to a uniquely named temporary sibling and rename, so readers never
+/// see partial files, even when the same asset is stored concurrently.
+fn write_atomic(path: &Path, bytes: &[u8]) -> Result<()> {
+    let storage = |source| Error::Storage {
+        path: path.to_path_buf(),
+        source,
+    };
+
+    let dir = path.parent().ok_or(Error::Internal("asset path has no parent"))?;
+    fs::create_dir_all(dir).map_err(storage)?;
+
+    // The temporary file is removed on drop if anything below fails.
+    let mut tmp = NamedTempFile::new_in(dir).map_err(storage)?;
+    tmp.write_all(bytes)
+        .and_then(|_| tmp.as_file().sync_all())
+        .map_err(storage)?;
+    tmp.persist(path).map_err(|e| storage(e.error))?;
+    Ok(())
+}
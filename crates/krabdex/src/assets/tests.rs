@@ -0,0 +1,78 @@
+use super::{sniff::{media_type, sniff}, Asset, AssetKind, AssetStore};
+
+const PNG: &[u8] = b"\x89PNG\r\n\x1a\n\0\0\0\rIHDR";
+const OGG: &[u8] = b"OggS\0\x02\0\0\0\0";
+
+#[test]
+fn kind_is_inferred_from_url_extension() {
+    let sprite = "https://raw.githubusercontent.com/PokeAPI/sprites/master/sprites/pokemon/25.png";
+    let cry = "https://raw.githubusercontent.com/PokeAPI/cries/main/cries/pokemon/latest/25.ogg";
+
+    assert_eq!(AssetKind::from_url(sprite), Some(AssetKind::Image));
+    assert_eq!(AssetKind::from_url(cry), Some(AssetKind::Audio));
+    assert_eq!(AssetKind::from_url("https://example.com/a.SVG?raw=1"), Some(AssetKind::Image));
+    assert_eq!(AssetKind::from_url("https://example.com/asset"), None);
+}
+
+#[test]
+fn sniffing_recognises_sprite_and_cry_formats() {
+    assert_eq!(sniff(PNG), Some((AssetKind::Image, "image/png")));
+    assert_eq!(sniff(b"GIF89a..."), Some((AssetKind::Image, "image/gif")));
+    assert_eq!(sniff(b"  <?xml version=\"1.0\"?>\n<svg xmlns=\"\"/>"), Some((AssetKind::Image, "image/svg+xml")));
+    assert_eq!(sniff(OGG), Some((AssetKind::Audio, "audio/ogg")));
+    assert_eq!(sniff(b"RIFF\0\0\0\0WAVEfmt "), Some((AssetKind::Audio, "audio/wav")));
+    assert_eq!(sniff(b"<!DOCTYPE html><html>"), None);
+}
+
+#[test]
+fn bytes_beat_the_content_type_header() {
+    // GitHub's raw host serves binaries as text/plain or octet-stream.
+    assert_eq!(media_type(Some("text/plain; charset=utf-8"), PNG), Some((AssetKind::Image, "image/png".into())));
+    assert_eq!(media_type(Some("image/avif"), b"...."), Some((AssetKind::Image, "image/avif".into())));
+    assert_eq!(media_type(Some("text/html"), b"<html>"), None);
+    assert_eq!(media_type(None, b"nope"), None);
+}
+
+#[test]
+fn store_is_content_addressed_and_deduplicated() {
+    let dir = tempfile::tempdir().unwrap();
+    let store = AssetStore::new(dir.path());
+    let asset = |url: &str| Asset {
+        url: url.into(),
+        kind: AssetKind::Image,
+        content_type: "image/png".into(),
+        bytes: PNG.to_vec(),
+    };
+
+    let first = store.put(&asset("https://example.com/a.png")).unwrap();
+    let second = store.put(&asset("https://example.com/b.png")).unwrap();
+
+    assert_eq!(first.sha256.len(), 64);
+    assert_eq!(first.sha256, second.sha256);
+    assert_eq!(first.path, second.path);
+    assert_eq!(second.url, "https://example.com/b.png");
+    assert_eq!(first.path, dir.path().join(&first.sha256[..2]).join(format!("{}.png", first.sha256)));
+    assert_eq!(std::fs::read(&first.path).unwrap(), PNG);
+    assert_eq!(first.size, PNG.len() as u64);
+}
+
+#[test]
+fn concurrent_puts_of_the_same_asset_do_not_collide() {
+    let dir = tempfile::tempdir().unwrap();
+    let store = AssetStore::new(dir.path());
+    let asset = Asset {
+        url: "https://example.com/a.png".into(),
+        kind: AssetKind::Image,
+        content_type: "image/png".into(),
+        bytes: PNG.to_vec(),
+    };
+
+    let stored: Vec<_> = std::thread::scope(|s| {
+        let handles: Vec<_> = (0..8).map(|_| s.spawn(|| store.put(&asset))).collect();
+        handles.into_iter().map(|h| h.join().unwrap().unwrap()).collect()
+    });
+
+    let shard = stored[0].path.parent().unwrap();
+    assert_eq!(std::fs::read_dir(shard).unwrap().count(), 1);
+    assert_eq!(std::fs::read(&stored[0].path).unwrap(), PNG);
+}
@@ -0,0 +1,232 @@
+use std::{future::Future, sync::Arc};
+
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+use crate::{
+    assets::{Asset, AssetStore, StoredAsset},
+    cache::CacheStats,
+    client::{builder::PokeApiClientBuilder, fetched::Fetched, options::RequestOptions},
+    error::{Error, Result},
+    http::Query,
+    index::{NameIndex, NameMatch},
+    models::{
+        common::{NamedApiResource, Page},
+        form::PokemonForm,
+        generation::Generation,
+        pokemon::Pokemon,
+        species::PokemonSpecies,
+    },
+    types::{
+        identifiers::{GenerationName, GenerationRef, PokemonName, PokemonRef, ResourceId},
+        pagination::PageRequest,
+    },
+};
+
+/// Blocking client for the PokeAPI.
+///
+/// Every call drives the async `crate::PokeApiClient` to completion on an
+/// internal single-threaded runtime. Do not call it from inside an async
+/// runtime; use the async client there instead.
+#[derive(Clone)]
+pub struct PokeApiClient {
+    inner: crate::PokeApiClient,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl PokeApiClient {
+    /// Create a client with default configuration.
+    pub fn new() -> Result<Self> {
+        Self::from_builder(crate::PokeApiClient::builder())
+    }
+
+    /// Build a blocking client from an async client builder.
+    pub fn from_builder(builder: PokeApiClientBuilder) -> Result<Self> {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .map_err(|source| Error::Build { component: "tokio runtime", source })?;
+
+        Ok(Self {
+            inner: builder.build()?,
+            runtime: Arc::new(runtime),
+        })
+    }
+
+    /// Derive a client that applies `options` to every call made through it.
+    pub fn with_options(&self, options: RequestOptions) -> Self {
+        Self {
+            inner: self.inner.with_options(options),
+            runtime: Arc::clone(&self.runtime),
+        }
+    }
+
+    /// Counts of fresh, revalidated and fetched responses (`None` if caching is disabled).
+    pub fn cache_stats(&self) -> Option<CacheStats> {
+        self.inner.cache_stats()
+    }
+
+    /// The async client this wrapper drives.
+    pub fn as_async(&self) -> &crate::PokeApiClient {
+        &self.inner
+    }
+
+    fn block_on<F: Future>(&self, fut: F) -> F::Output {
+        self.runtime.block_on(fut)
+    }
+
+    /// Fetch a Pokemon by numeric id.
+    pub fn pokemon_by_id(&self, id: u32) -> Result<Pokemon> {
+        self.block_on(self.inner.pokemon_by_id(id))
+    }
+
+    /// Fetch a Pokemon by validated name (lowercase, hyphenated).
+    pub fn pokemon_by_name<'a>(&self, name: impl Into<PokemonName<'a>>) -> Result<Pokemon> {
+        self.block_on(self.inner.pokemon_by_name(name))
+    }
+
+    /// Fetch a Pokemon by id or name (convenience API).
+    pub fn pokemon<'a>(&self, pokemon: impl Into<PokemonRef<'a>>) -> Result<Pokemon> {
+        self.block_on(self.inner.pokemon(pokemon))
+    }
+
+    /// Fetch a Pokemon together with the raw upstream response.
+    pub fn pokemon_fetched<'a>(&self, pokemon: impl Into<PokemonRef<'a>>) -> Result<Fetched<Pokemon>> {
+        self.block_on(self.inner.pokemon_fetched(pokemon))
+    }
+
+    /// List Pokemon resources (name+url) with pagination.
+    pub fn pokemon_list(&self, page: PageRequest) -> Result<Page<NamedApiResource>> {
+        self.block_on(self.inner.pokemon_list(page))
+    }
+
+    /// Fetch a Pokemon form by numeric id.
+    pub fn pokemon_form_by_id(&self, id: u32) -> Result<PokemonForm> {
+        self.block_on(self.inner.pokemon_form_by_id(id))
+    }
+
+    /// Fetch a Pokemon form by name (e.g. `charizard-mega-x`).
+    pub fn pokemon_form_by_name<'a>(&self, name: impl Into<PokemonName<'a>>) -> Result<PokemonForm> {
+        self.block_on(self.inner.pokemon_form_by_name(name))
+    }
+
+    /// Fetch a Pokemon form by id or name (convenience API).
+    pub fn pokemon_form<'a>(&self, form: impl Into<PokemonRef<'a>>) -> Result<PokemonForm> {
+        self.block_on(self.inner.pokemon_form(form))
+    }
+
+    /// Resolve every entry of `pokemon.forms` into a `PokemonForm`, in order.
+    pub fn pokemon_forms(&self, pokemon: &Pokemon) -> Result<Vec<PokemonForm>> {
+        self.block_on(self.inner.pokemon_forms(pokemon))
+    }
+
+    /// List Pokemon forms (name+url) with pagination.
+    pub fn pokemon_form_list(&self, page: PageRequest) -> Result<Page<NamedApiResource>> {
+        self.block_on(self.inner.pokemon_form_list(page))
+    }
+
+    /// Fetch a Pokemon species by numeric id.
+    pub fn pokemon_species_by_id(&self, id: u32) -> Result<PokemonSpecies> {
+        self.block_on(self.inner.pokemon_species_by_id(id))
+    }
+
+    /// Fetch a Pokemon species by name.
+    pub fn pokemon_species_by_name<'a>(&self, name: impl Into<PokemonName<'a>>) -> Result<PokemonSpecies> {
+        self.block_on(self.inner.pokemon_species_by_name(name))
+    }
+
+    /// Fetch a Pokemon species by id or name (convenience API).
+    pub fn pokemon_species<'a>(&self, species: impl Into<PokemonRef<'a>>) -> Result<PokemonSpecies> {
+        self.block_on(self.inner.pokemon_species(species))
+    }
+
+    /// Every variety of a species fetched as full `Pokemon`, default variety first.
+    pub fn pokemon_varieties<'a>(&self, species: impl Into<PokemonRef<'a>>) -> Result<Vec<Pokemon>> {
+        self.block_on(self.inner.pokemon_varieties(species))
+    }
+
+    /// List Pokemon species (name+url) with pagination.
+    pub fn pokemon_species_list(&self, page: PageRequest) -> Result<Page<NamedApiResource>> {
+        self.block_on(self.inner.pokemon_species_list(page))
+    }
+
+    /// Fetch a Generation by numeric id.
+    pub fn generation_by_id(&self, id: u32) -> Result<Generation> {
+        self.block_on(self.inner.generation_by_id(id))
+    }
+
+    /// Fetch a Generation by validated name.
+    pub fn generation_by_name<'a>(&self, name: impl Into<GenerationName<'a>>) -> Result<Generation> {
+        self.block_on(self.inner.generation_by_name(name))
+    }
+
+    /// Fetch a Generation by id or name (convenience API).
+    pub fn generation<'a>(&self, gen: impl Into<GenerationRef<'a>>) -> Result<Generation> {
+        self.block_on(self.inner.generation(gen))
+    }
+
+    /// Fetch a Generation together with the raw upstream response.
+    pub fn generation_fetched<'a>(&self, gen: impl Into<GenerationRef<'a>>) -> Result<Fetched<Generation>> {
+        self.block_on(self.inner.generation_fetched(gen))
+    }
+
+    /// List generations (name+url) with pagination.
+    pub fn generation_list(&self, page: PageRequest) -> Result<Page<NamedApiResource>> {
+        self.block_on(self.inner.generation_list(page))
+    }
+
+    /// Fetch any endpoint as untyped JSON.
+    pub fn get_raw(&self, path: &str, query: Option<Query>) -> Result<Value> {
+        self.block_on(self.inner.get_raw(path, query))
+    }
+
+    /// Fetch any endpoint and deserialize it into a caller-provided type.
+    pub fn get_typed<T: DeserializeOwned>(&self, path: &str, query: Option<Query>) -> Result<T> {
+        self.block_on(self.inner.get_typed(path, query))
+    }
+
+    /// Fetch any endpoint, keeping the raw response alongside the typed value.
+    pub fn get_fetched<T: DeserializeOwned>(&self, path: &str, query: Option<Query>) -> Result<Fetched<T>> {
+        self.block_on(self.inner.get_fetched(path, query))
+    }
+
+    /// Kind and id of a resource URL from this client's PokeAPI instance.
+    pub fn resource_id(&self, url: &str) -> Result<ResourceId> {
+        self.inner.resource_id(url)
+    }
+
+    /// Turn a Pokemon link into a `PokemonRef`.
+    pub fn pokemon_ref(&self, resource: &NamedApiResource) -> Result<PokemonRef<'static>> {
+        self.inner.pokemon_ref(resource)
+    }
+
+    /// Turn a generation link into a `GenerationRef`.
+    pub fn generation_ref(&self, resource: &NamedApiResource) -> Result<GenerationRef<'static>> {
+        self.inner.generation_ref(resource)
+    }
+
+    /// Every name of a resource kind, loaded once and cached on the client.
+    pub fn name_index(&self, resource: &'static str) -> Result<Arc<NameIndex>> {
+        self.block_on(self.inner.name_index(resource))
+    }
+
+    /// Search Pokemon names, best match first.
+    pub fn search_pokemon(&self, query: &str, limit: usize) -> Result<Vec<NameMatch>> {
+        self.block_on(self.inner.search_pokemon(query, limit))
+    }
+
+    /// Search generation names, best match first.
+    pub fn search_generations(&self, query: &str, limit: usize) -> Result<Vec<NameMatch>> {
+        self.block_on(self.inner.search_generations(query, limit))
+    }
+
+    /// Download a sprite or cry and verify it is media.
+    pub fn fetch_asset(&self, url: &str) -> Result<Asset> {
+        self.block_on(self.inner.fetch_asset(url))
+    }
+
+    /// Download an asset and write it into a content-addressed `store`.
+    pub fn download_asset(&self, url: &str, store: &AssetStore) -> Result<StoredAsset> {
+        self.block_on(self.inner.download_asset(url, store))
+    }
+}
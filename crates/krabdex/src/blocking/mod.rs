@@ -0,0 +1,14 @@
+//! Synchronous wrapper around the async client (requires the `blocking` feature).
+//!
+//! ```no_run
+//! # fn main() -> krabdex::Result<()> {
+//! let client = krabdex::blocking::PokeApiClient::new()?;
+//! let pikachu = client.pokemon_by_id(25)?;
+//! println!("{}", pikachu.name);
+//! # Ok(())
+//! # }
+//! ```
+
+pub mod client;
+
+pub use client::PokeApiClient;
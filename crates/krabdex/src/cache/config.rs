@@ -0,0 +1,73 @@
+use std::time::Duration;
+
+/// How the cache decides how long a response stays fresh.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CachePolicy {
+    /// Every response is fresh for `CacheConfig::ttl`, whatever the server says.
+    #[default]
+    FixedTtl,
+    /// Freshness comes from `Cache-Control: max-age`, `Expires` and `Age`.
+    /// `CacheConfig::ttl` is used when the server gives no freshness information.
+    /// `no-store` responses are never cached.
+    ServerDirected,
+}
+
+/// Settings for the in-memory response cache.
+#[derive(Debug, Clone)]
+pub struct CacheConfig {
+    /// How long a stored response is served without contacting the server
+    /// (the fallback lifetime under `CachePolicy::ServerDirected`).
+    pub ttl: Duration,
+    /// Where freshness lifetimes come from.
+    pub policy: CachePolicy,
+    /// Lower bound applied to every computed lifetime, except for `no-cache`
+    /// responses, which are always revalidated.
+    pub min_ttl: Option<Duration>,
+    /// Upper bound applied to every computed lifetime.
+    pub max_ttl: Option<Duration>,
+    /// Maximum number of responses kept in memory (oldest evicted first).
+    pub max_entries: usize,
+}
+
+impl Default for CacheConfig {
+    fn default() -> Self {
+        Self {
+            ttl: Duration::from_secs(300),
+            policy: CachePolicy::default(),
+            min_ttl: None,
+            max_ttl: None,
+            max_entries: 1024,
+        }
+    }
+}
+
+/// How a single request uses the response cache.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CacheMode {
+    /// Serve fresh entries, revalidate stale ones and store new responses.
+    #[default]
+    Default,
+    /// Neither read from nor write to the cache.
+    Bypass,
+    /// Skip the cached entry, fetch unconditionally and store the result.
+    ForceRefresh,
+}
+
+/// How a response was obtained.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CacheStatus {
+    /// Served from the cache without contacting the server.
+    Fresh,
+    /// A stale cache entry was confirmed unchanged by the server (`304 Not Modified`).
+    Revalidated,
+    /// Fetched from the server (cache miss, or the server sent a new representation).
+    Fetched,
+}
+
+/// Running totals of how responses were obtained.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CacheStats {
+    pub fresh: u64,
+    pub revalidated: u64,
+    pub fetched: u64,
+}
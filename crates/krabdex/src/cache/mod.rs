@@ -0,0 +1,8 @@
+pub mod config;
+pub(crate) mod policy;
+pub(crate) mod store;
+
+pub use config::{CacheConfig, CacheMode, CachePolicy, CacheStats, CacheStatus};
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,89 @@
+use std::time::{Duration, SystemTime};
+
+use crate::{
+    cache::config::{CacheConfig, CachePolicy},
+    http::Headers,
+};
+
+/// Compute how long a response stays fresh, or `None` if it must not be stored.
+pub(crate) fn freshness_lifetime(
+    config: &CacheConfig,
+    headers: &Headers,
+    now: SystemTime,
+) -> Option<Duration> {
+    let lifetime = match config.policy {
+        CachePolicy::FixedTtl => config.ttl,
+        CachePolicy::ServerDirected => {
+            let directives = CacheControl::parse(headers.get("cache-control").unwrap_or(""));
+            if directives.no_store {
+                return None;
+            }
+            if directives.no_cache {
+                // Always revalidate: the `min_ttl` floor must not make it fresh.
+                return Some(Duration::ZERO);
+            }
+            server_lifetime(&directives, headers, now).unwrap_or(config.ttl)
+        }
+    };
+
+    Some(clamp(lifetime, config.min_ttl, config.max_ttl))
+}
+
+/// Freshness lifetime as stated by the server, minus the response's current age.
+fn server_lifetime(directives: &CacheControl, headers: &Headers, now: SystemTime) -> Option<Duration> {
+    let lifetime = if let Some(max_age) = directives.max_age {
+        Duration::from_secs(max_age)
+    } else {
+        let expires = headers.get("expires")?;
+        // Per RFC 9111, an invalid `Expires` value means "already expired".
+        let Ok(expires) = httpdate::parse_http_date(expires) else {
+            return Some(Duration::ZERO);
+        };
+        let date = headers
+            .get("date")
+            .and_then(|d| httpdate::parse_http_date(d).ok())
+            .unwrap_or(now);
+        expires.duration_since(date).unwrap_or(Duration::ZERO)
+    };
+
+    let age = headers
+        .get("age")
+        .and_then(|a| a.trim().parse().ok())
+        .map(Duration::from_secs)
+        .unwrap_or(Duration::ZERO);
+
+    Some(lifetime.saturating_sub(age))
+}
+
+fn clamp(lifetime: Duration, min: Option<Duration>, max: Option<Duration>) -> Duration {
+    let lifetime = min.map_or(lifetime, |min| lifetime.max(min));
+    max.map_or(lifetime, |max| lifetime.min(max))
+}
+
+/// The `Cache-Control` response directives the cache understands.
+#[derive(Debug, Default, PartialEq, Eq)]
+struct CacheControl {
+    max_age: Option<u64>,
+    no_cache: bool,
+    no_store: bool,
+}
+
+impl CacheControl {
+    fn parse(value: &str) -> Self {
+        let mut cc = Self::default();
+        for directive in value.split(',') {
+            let (name, arg) = match directive.split_once('=') {
+                Some((name, arg)) => (name.trim(), Some(arg.trim().trim_matches('"'))),
+                None => (directive.trim(), None),
+            };
+
+            match name.to_ascii_lowercase().as_str() {
+                "max-age" => cc.max_age = arg.and_then(|a| a.parse().ok()),
+                "no-cache" => cc.no_cache = true,
+                "no-store" => cc.no_store = true,
+                _ => {}
+            }
+        }
+        cc
+    }
+}
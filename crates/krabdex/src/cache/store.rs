@@ -0,0 +1,154 @@
+use std::{
+    collections::HashMap,
+    sync::Mutex,
+    time::{Duration, Instant, SystemTime},
+};
+
+use crate::{
+    cache::{
+        config::{CacheConfig, CacheStats, CacheStatus},
+        policy::freshness_lifetime,
+    },
+    http::{Headers, HttpRequest, HttpResponse},
+};
+
+struct CacheEntry {
+    response: HttpResponse,
+    stored_at: Instant,
+    ttl: Duration,
+}
+
+/// Result of looking a request up in the cache.
+pub(crate) enum Lookup {
+    /// Entry is within its TTL and can be served as-is.
+    Fresh(HttpResponse),
+    /// Entry expired but carries validators; send them to revalidate.
+    Stale(Headers),
+    /// Nothing usable in the cache.
+    Miss,
+}
+
+/// In-memory response cache keyed by request URL and query.
+pub(crate) struct ResponseCache {
+    config: CacheConfig,
+    entries: Mutex<HashMap<String, CacheEntry>>,
+    stats: Mutex<CacheStats>,
+}
+
+impl ResponseCache {
+    pub fn new(config: CacheConfig) -> Self {
+        Self {
+            config,
+            entries: Mutex::new(HashMap::new()),
+            stats: Mutex::new(CacheStats::default()),
+        }
+    }
+
+    /// Build the cache key for a request (URL plus sorted query pairs).
+    pub fn key(req: &HttpRequest) -> String {
+        let mut key = req.url.to_string();
+        for (i, (k, v)) in req.query.iter().enumerate() {
+            key.push(if i == 0 { '?' } else { '&' });
+            key.push_str(k);
+            key.push('=');
+            key.push_str(v);
+        }
+        key
+    }
+
+    pub fn lookup(&self, key: &str) -> Lookup {
+        let entries = self.entries.lock().expect("cache lock poisoned");
+        let Some(entry) = entries.get(key) else {
+            return Lookup::Miss;
+        };
+
+        if entry.stored_at.elapsed() < entry.ttl {
+            return Lookup::Fresh(entry.response.clone());
+        }
+
+        let validators = conditional_headers(&entry.response.headers);
+        if validators.iter().next().is_some() {
+            Lookup::Stale(validators)
+        } else {
+            Lookup::Miss
+        }
+    }
+
+    /// Store a successful response, evicting the oldest entry when full.
+    ///
+    /// Responses the policy refuses to cache (`no-store`) replace nothing and
+    /// drop any previous entry for the same key.
+    pub fn store(&self, key: String, response: HttpResponse) {
+        let mut entries = self.entries.lock().expect("cache lock poisoned");
+        let ttl = match freshness_lifetime(&self.config, &response.headers, SystemTime::now()) {
+            Some(ttl) if self.config.max_entries > 0 => ttl,
+            _ => {
+                entries.remove(&key);
+                return;
+            }
+        };
+
+        if !entries.contains_key(&key) && entries.len() >= self.config.max_entries {
+            let oldest = entries
+                .iter()
+                .min_by_key(|(_, e)| e.stored_at)
+                .map(|(k, _)| k.clone());
+            if let Some(oldest) = oldest {
+                entries.remove(&oldest);
+            }
+        }
+
+        entries.insert(key, CacheEntry { response, stored_at: Instant::now(), ttl });
+    }
+
+    /// Handle a `304 Not Modified`: merge the new headers into the entry,
+    /// restart its lifetime and return the cached response.
+    ///
+    /// Returns `None` if the entry was evicted in the meantime.
+    pub fn revalidate(&self, key: &str, not_modified: &HttpResponse) -> Option<HttpResponse> {
+        let mut entries = self.entries.lock().expect("cache lock poisoned");
+        let entry = entries.get_mut(key)?;
+
+        for (k, v) in not_modified.headers.iter() {
+            entry.response.headers.insert(k, v);
+        }
+        let response = entry.response.clone();
+
+        match freshness_lifetime(&self.config, &response.headers, SystemTime::now()) {
+            Some(ttl) => {
+                entry.stored_at = Instant::now();
+                entry.ttl = ttl;
+            }
+            None => {
+                entries.remove(key);
+            }
+        }
+
+        Some(response)
+    }
+
+    pub fn record(&self, status: CacheStatus) {
+        let mut stats = self.stats.lock().expect("cache lock poisoned");
+        match status {
+            CacheStatus::Fresh => stats.fresh += 1,
+            CacheStatus::Revalidated => stats.revalidated += 1,
+            CacheStatus::Fetched => stats.fetched += 1,
+        }
+    }
+
+    pub fn stats(&self) -> CacheStats {
+        *self.stats.lock().expect("cache lock poisoned")
+    }
+}
+
+/// Turn stored `ETag` / `Last-Modified` headers into request validators.
+fn conditional_headers(stored: &Headers) -> Headers {
+    let mut headers = Headers::new();
+    if let Some(etag) = stored.get("etag") {
+        headers.insert("if-none-match", etag);
+    }
+    if let Some(last_modified) = stored.get("last-modified") {
+        headers.insert("if-modified-since", last_modified);
+    }
+    headers
+}
@@ -0,0 +1,231 @@
+use std::time::{Duration, SystemTime};
+
+use url::Url;
+
+use crate::{
+    cache::{
+        config::{CacheConfig, CachePolicy, CacheStatus},
+        policy::freshness_lifetime,
+        store::{Lookup, ResponseCache},
+    },
+    http::{Headers, HttpRequest, HttpResponse, Method},
+};
+
+fn make_resp(headers: &[(&str, &str)], body: &[u8]) -> HttpResponse {
+    let mut h = Headers::new();
+    for (k, v) in headers {
+        h.insert(*k, *v);
+    }
+    HttpResponse {
+        status: 200,
+        headers: h,
+        body: body.to_vec(),
+    }
+}
+
+fn cache_with_ttl(ttl: Duration) -> ResponseCache {
+    ResponseCache::new(CacheConfig { ttl, ..CacheConfig::default() })
+}
+
+#[test]
+fn key_includes_sorted_query() {
+    let mut req = HttpRequest::new(
+        Method::Get,
+        Url::parse("https://pokeapi.co/api/v2/pokemon").unwrap(),
+    );
+    req.query.set("offset", "20");
+    req.query.set("limit", "10");
+
+    assert_eq!(
+        ResponseCache::key(&req),
+        "https://pokeapi.co/api/v2/pokemon?limit=10&offset=20"
+    );
+}
+
+#[test]
+fn fresh_entries_are_served_from_cache() {
+    let cache = cache_with_ttl(Duration::from_secs(60));
+    cache.store("k".into(), make_resp(&[], b"cached"));
+
+    match cache.lookup("k") {
+        Lookup::Fresh(resp) => assert_eq!(resp.body, b"cached"),
+        _ => panic!("expected fresh entry"),
+    }
+}
+
+#[test]
+fn expired_entries_with_validators_are_stale() {
+    let cache = cache_with_ttl(Duration::ZERO);
+    cache.store(
+        "k".into(),
+        make_resp(&[("etag", "\"abc\""), ("last-modified", "Wed, 21 Oct 2015 07:28:00 GMT")], b""),
+    );
+
+    match cache.lookup("k") {
+        Lookup::Stale(h) => {
+            assert_eq!(h.get("if-none-match"), Some("\"abc\""));
+            assert_eq!(h.get("if-modified-since"), Some("Wed, 21 Oct 2015 07:28:00 GMT"));
+        }
+        _ => panic!("expected stale entry"),
+    }
+}
+
+#[test]
+fn expired_entries_without_validators_are_misses() {
+    let cache = cache_with_ttl(Duration::ZERO);
+    cache.store("k".into(), make_resp(&[], b""));
+
+    assert!(matches!(cache.lookup("k"), Lookup::Miss));
+}
+
+#[test]
+fn revalidate_merges_headers_and_keeps_body() {
+    let cache = cache_with_ttl(Duration::ZERO);
+    cache.store("k".into(), make_resp(&[("etag", "\"v1\"")], b"body"));
+
+    let not_modified = HttpResponse {
+        status: 304,
+        headers: {
+            let mut h = Headers::new();
+            h.insert("etag", "\"v2\"");
+            h
+        },
+        body: Vec::new(),
+    };
+
+    let resp = cache.revalidate("k", &not_modified).unwrap();
+    assert_eq!(resp.status, 200);
+    assert_eq!(resp.body, b"body");
+    assert_eq!(resp.headers.get("etag"), Some("\"v2\""));
+    assert!(cache.revalidate("missing", &not_modified).is_none());
+}
+
+#[test]
+fn store_evicts_oldest_when_full() {
+    let cache = ResponseCache::new(CacheConfig {
+        ttl: Duration::from_secs(60),
+        max_entries: 1,
+        ..CacheConfig::default()
+    });
+    cache.store("a".into(), make_resp(&[], b"a"));
+    cache.store("b".into(), make_resp(&[], b"b"));
+
+    assert!(matches!(cache.lookup("a"), Lookup::Miss));
+    assert!(matches!(cache.lookup("b"), Lookup::Fresh(_)));
+}
+
+#[test]
+fn record_updates_stats() {
+    let cache = cache_with_ttl(Duration::from_secs(60));
+    cache.record(CacheStatus::Fresh);
+    cache.record(CacheStatus::Fetched);
+    cache.record(CacheStatus::Fetched);
+
+    let stats = cache.stats();
+    assert_eq!((stats.fresh, stats.revalidated, stats.fetched), (1, 0, 2));
+}
+
+fn server_directed() -> CacheConfig {
+    CacheConfig {
+        ttl: Duration::from_secs(30),
+        policy: CachePolicy::ServerDirected,
+        ..CacheConfig::default()
+    }
+}
+
+fn headers(pairs: &[(&str, &str)]) -> Headers {
+    make_resp(pairs, b"").headers
+}
+
+#[test]
+fn fixed_policy_ignores_server_headers() {
+    let config = CacheConfig { ttl: Duration::from_secs(30), ..CacheConfig::default() };
+    let h = headers(&[("cache-control", "no-store")]);
+
+    assert_eq!(freshness_lifetime(&config, &h, SystemTime::now()), Some(Duration::from_secs(30)));
+}
+
+#[test]
+fn server_policy_uses_max_age_minus_age() {
+    let h = headers(&[("cache-control", "public, max-age=600"), ("age", "100")]);
+
+    assert_eq!(
+        freshness_lifetime(&server_directed(), &h, SystemTime::now()),
+        Some(Duration::from_secs(500))
+    );
+}
+
+#[test]
+fn server_policy_uses_expires_relative_to_date() {
+    let h = headers(&[
+        ("date", "Wed, 21 Oct 2015 07:28:00 GMT"),
+        ("expires", "Wed, 21 Oct 2015 08:28:00 GMT"),
+    ]);
+
+    assert_eq!(
+        freshness_lifetime(&server_directed(), &h, SystemTime::now()),
+        Some(Duration::from_secs(3600))
+    );
+}
+
+#[test]
+fn server_policy_treats_invalid_expires_as_expired() {
+    let h = headers(&[("expires", "0")]);
+
+    assert_eq!(freshness_lifetime(&server_directed(), &h, SystemTime::now()), Some(Duration::ZERO));
+}
+
+#[test]
+fn server_policy_honours_no_store_and_no_cache() {
+    let config = server_directed();
+
+    assert_eq!(freshness_lifetime(&config, &headers(&[("cache-control", "no-store")]), SystemTime::now()), None);
+    assert_eq!(
+        freshness_lifetime(&config, &headers(&[("cache-control", "no-cache, max-age=60")]), SystemTime::now()),
+        Some(Duration::ZERO)
+    );
+}
+
+#[test]
+fn server_policy_falls_back_to_ttl() {
+    assert_eq!(
+        freshness_lifetime(&server_directed(), &headers(&[]), SystemTime::now()),
+        Some(Duration::from_secs(30))
+    );
+}
+
+#[test]
+fn lifetimes_are_clamped() {
+    let config = CacheConfig {
+        min_ttl: Some(Duration::from_secs(60)),
+        max_ttl: Some(Duration::from_secs(120)),
+        ..server_directed()
+    };
+
+    let short = headers(&[("cache-control", "max-age=1")]);
+    let long = headers(&[("cache-control", "max-age=86400")]);
+
+    assert_eq!(freshness_lifetime(&config, &short, SystemTime::now()), Some(Duration::from_secs(60)));
+    assert_eq!(freshness_lifetime(&config, &long, SystemTime::now()), Some(Duration::from_secs(120)));
+}
+
+#[test]
+fn no_cache_responses_ignore_min_ttl() {
+    let config = CacheConfig {
+        min_ttl: Some(Duration::from_secs(60)),
+        ..server_directed()
+    };
+
+    assert_eq!(
+        freshness_lifetime(&config, &headers(&[("cache-control", "no-cache")]), SystemTime::now()),
+        Some(Duration::ZERO)
+    );
+}
+
+#[test]
+fn no_store_responses_are_not_cached() {
+    let cache = ResponseCache::new(server_directed());
+    cache.store("k".into(), make_resp(&[("cache-control", "no-store")], b""));
+
+    assert!(matches!(cache.lookup("k"), Lookup::Miss));
+}
@@ -1,9 +1,15 @@
+use std::{collections::HashMap, sync::{Arc, Mutex}};
+
 use ::url::Url;
 
 use crate::{
-    client::{config::ClientConfig, client::PokeApiClient},
-    error::{Error, Result},
-    transport::reqwest_transport::ReqwestTransport
+    cache::{CacheConfig, CachePolicy, store::ResponseCache},
+    client::{config::ClientConfig, client::{ClientInner, PokeApiClient}, options::RequestOptions, retry::RetryConfig},
+    decode::DeserializeMode,
+    error::Result,
+    metrics::MetricsObserver,
+    middleware::{Middleware, middleware::MiddlewareStack},
+    transport::reqwest_transport::{ReqwestTransport, transport_error},
 };
 
 /// Builder for constructing a `PokeApiClient` with custom settings.
@@ -12,6 +18,8 @@ pub struct PokeApiClientBuilder {
     config: ClientConfig,
     timeout: Option<std::time::Duration>,
     user_agent: Option<String>,
+    metrics: Option<Arc<dyn MetricsObserver>>,
+    middleware: MiddlewareStack,
 }
 
 impl Default for PokeApiClientBuilder {
@@ -20,6 +28,8 @@ impl Default for PokeApiClientBuilder {
             config: ClientConfig::default(),
             timeout: Some(std::time::Duration::from_secs(10)),
             user_agent: Some("krabdex/0.1.0".to_string()),
+            metrics: None,
+            middleware: MiddlewareStack::default(),
         }
     }
 }
@@ -36,6 +46,8 @@ impl PokeApiClientBuilder {
             config,
             timeout: Some(std::time::Duration::from_secs(10)),
             user_agent: Some("krabdex/0.1.0".to_string()),
+            metrics: None,
+            middleware: MiddlewareStack::default(),
         }
     }
 
@@ -57,6 +69,80 @@ impl PokeApiClientBuilder {
         self
     }
 
+    /// Enable the in-memory response cache.
+    ///
+    /// Expired entries are revalidated with `If-None-Match` / `If-Modified-Since`
+    /// when the server supplied an `ETag` or `Last-Modified` header.
+    pub fn cache(mut self, cache: CacheConfig) -> Self {
+        self.config.cache = Some(cache);
+        self
+    }
+
+    /// Enable the response cache with the given TTL and default settings otherwise.
+    pub fn cache_ttl(mut self, ttl: std::time::Duration) -> Self {
+        self.config.cache.get_or_insert_with(CacheConfig::default).ttl = ttl;
+        self
+    }
+
+    /// Choose between a fixed TTL and server-directed (`Cache-Control`) freshness.
+    pub fn cache_policy(mut self, policy: CachePolicy) -> Self {
+        self.config.cache.get_or_insert_with(CacheConfig::default).policy = policy;
+        self
+    }
+
+    /// Never keep a cached response fresh for less than `min`.
+    pub fn cache_min_ttl(mut self, min: std::time::Duration) -> Self {
+        self.config.cache.get_or_insert_with(CacheConfig::default).min_ttl = Some(min);
+        self
+    }
+
+    /// Never keep a cached response fresh for longer than `max`.
+    pub fn cache_max_ttl(mut self, max: std::time::Duration) -> Self {
+        self.config.cache.get_or_insert_with(CacheConfig::default).max_ttl = Some(max);
+        self
+    }
+
+    /// Configure retries for transient failures (off by default).
+    pub fn retry(mut self, retry: RetryConfig) -> Self {
+        self.config.retry = retry;
+        self
+    }
+
+    /// Set the number of retries after the first attempt (0 disables retrying).
+    pub fn max_retries(mut self, n: u32) -> Self {
+        self.config.retry.max_retries = n;
+        self
+    }
+
+    /// Register an observer notified with metrics for every API call.
+    pub fn metrics(mut self, observer: Arc<dyn MetricsObserver>) -> Self {
+        self.metrics = Some(observer);
+        self
+    }
+
+    /// Append a middleware layer around the transport.
+    ///
+    /// Layers see requests in the order they were added and responses in reverse.
+    pub fn middleware(mut self, middleware: impl Middleware + 'static) -> Self {
+        self.middleware.push(Arc::new(middleware));
+        self
+    }
+
+    /// Choose strict, standard or lenient deserialization of responses.
+    pub fn deserialize_mode(mut self, mode: DeserializeMode) -> Self {
+        self.config.deserialize_mode = mode;
+        self
+    }
+
+    /// Suggest similar names when a lookup by name returns `404`.
+    ///
+    /// The first miss for a resource kind downloads its full name list (one
+    /// paginated list call, cached on the client), so this is off by default.
+    pub fn not_found_suggestions(mut self, enabled: bool) -> Self {
+        self.config.not_found_suggestions = enabled;
+        self
+    }
+
     /// Set a client-wide timeout.
     pub fn timeout(mut self, d: std::time::Duration) -> Self {
         self.timeout = Some(d);
@@ -82,13 +168,27 @@ impl PokeApiClientBuilder {
 
         let client = rb
             .build()
-            .map_err(|e| Error::Transport { source: Box::new(e) })?;
+            .map_err(transport_error)?;
 
         let transport = ReqwestTransport::new(client);
+        let cache = self.config.cache.clone().map(ResponseCache::new);
 
         Ok(PokeApiClient {
-            config: self.config,
-            transport,
+            inner: Arc::new(ClientInner {
+                config: self.config,
+                transport,
+                cache,
+                metrics: self.metrics,
+                middleware: self.middleware,
+                name_indexes: Mutex::new(HashMap::new()),
+            }),
+            options: RequestOptions::default(),
         })
     }
+
+    /// Build a `blocking::PokeApiClient` from the accumulated configuration.
+    #[cfg(feature = "blocking")]
+    pub fn build_blocking(self) -> Result<crate::blocking::PokeApiClient> {
+        crate::blocking::PokeApiClient::from_builder(self)
+    }
 }
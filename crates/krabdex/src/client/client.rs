@@ -1,17 +1,60 @@
+use std::{collections::HashMap, sync::{Arc, Mutex}, time::Instant};
+
+use backoff::backoff::Backoff;
 use serde::de::DeserializeOwned;
+use tokio::sync::OnceCell;
+use tracing::{field, Instrument, Span};
 
 use crate::{
-    client::{config::ClientConfig, builder::PokeApiClientBuilder},
-    error::{Error, Result, classify::classify_http_error},
-    http::{HttpRequest, Method, Query, url::join_base},
-    transport::transport::Transport,
+    cache::{CacheMode, CacheStats, CacheStatus, store::{Lookup, ResponseCache}},
+    client::{
+        config::ClientConfig,
+        builder::PokeApiClientBuilder,
+        fetched::Fetched,
+        options::RequestOptions,
+        pagination::page_query,
+        retry::{is_retryable_status, retry_after},
+    },
+    decode::decoder::{decode, DecodeError},
+    error::{ApiError, ApiErrorKind, Error, Result, classify::{Target, classify_http_error}},
+    http::{HttpRequest, HttpResponse, Method, Query, url::join_base},
+    index::index::NameIndex,
+    models::common::{NamedApiResource, Page},
+    metrics::{MetricsObserver, RequestMetrics, observer::endpoint_of},
+    middleware::middleware::MiddlewareStack,
     transport::reqwest_transport::ReqwestTransport,
+    types::pagination::{Limit, Offset, PageRequest},
 };
 
 /// Async client for interacting with the PokeAPI.
+///
+/// Cloning is cheap: clones share the same transport, configuration and cache,
+/// so a client can be stored directly in application state or moved into tasks.
+#[derive(Clone)]
 pub struct PokeApiClient {
+    pub(crate) inner: Arc<ClientInner>,
+    pub(crate) options: RequestOptions,
+}
+
+/// State shared by every clone of a `PokeApiClient`.
+pub(crate) struct ClientInner {
     pub(crate) config: ClientConfig,
     pub(crate) transport: ReqwestTransport,
+    pub(crate) cache: Option<ResponseCache>,
+    pub(crate) metrics: Option<Arc<dyn MetricsObserver>>,
+    pub(crate) middleware: MiddlewareStack,
+    /// Name lists per resource kind, loaded once on first use (concurrent
+    /// first calls wait for the same load).
+    pub(crate) name_indexes: Mutex<HashMap<&'static str, Arc<OnceCell<Arc<NameIndex>>>>>,
+}
+
+/// What happened while serving one call, for spans and metrics.
+#[derive(Debug, Default)]
+struct CallStats {
+    status: Option<u16>,
+    attempts: u32,
+    bytes_received: u64,
+    cache_status: Option<CacheStatus>,
 }
 
 impl PokeApiClient {
@@ -25,32 +68,343 @@ impl PokeApiClient {
         PokeApiClientBuilder::new()
     }
 
+    /// Derive a client that applies `options` to every call made through it.
+    ///
+    /// The derived client shares state with `self`; its options replace any
+    /// options previously set on `self`.
+    pub fn with_options(&self, options: RequestOptions) -> Self {
+        Self {
+            inner: Arc::clone(&self.inner),
+            options,
+        }
+    }
+
+    /// Counts of fresh, revalidated and fetched responses (`None` if caching is disabled).
+    pub fn cache_stats(&self) -> Option<CacheStats> {
+        self.inner.cache.as_ref().map(ResponseCache::stats)
+    }
+
     /// Core internal helper: build URL, add headers/query, call transport, handle errors, deserialize.
     pub(crate) async fn get_json<T: DeserializeOwned>(
         &self,
         path: &str,
         query: Option<Query>
     ) -> Result<T> {
-        let url = join_base(&self.config.base_url, &self.config.api_prefix, path)?;
-        let url_string = url.to_string();
+        self.fetch_json(path, query).await.map(|fetched| fetched.value)
+    }
 
-        let mut req = HttpRequest::new(Method::Get, url);
-        req.headers = self.config.default_headers.clone();
-        if let Some(q) = query {
-            req.query = q;
+    /// Like `get_json`, but keeps the raw response alongside the typed value.
+    pub(crate) async fn fetch_json<T: DeserializeOwned>(
+        &self,
+        path: &str,
+        query: Option<Query>
+    ) -> Result<Fetched<T>> {
+        self.fetch(path, query, None).await
+    }
+
+    /// Fetch a single resource; a `404` is reported against `target`.
+    pub(crate) async fn get_resource<T: DeserializeOwned>(&self, target: Target) -> Result<T> {
+        self.fetch_resource(target).await.map(|fetched| fetched.value)
+    }
+
+    /// Like `get_resource`, but keeps the raw response alongside the typed value.
+    pub(crate) async fn fetch_resource<T: DeserializeOwned>(
+        &self,
+        target: Target,
+    ) -> Result<Fetched<T>> {
+        self.fetch(&target.path(), None, Some(&target)).await
+    }
+
+    /// Each call runs inside a `krabdex.request` span recording method, path,
+    /// status, attempt, cache outcome and latency.
+    async fn fetch<T: DeserializeOwned>(
+        &self,
+        path: &str,
+        query: Option<Query>,
+        target: Option<&Target>,
+    ) -> Result<Fetched<T>> {
+        let span = tracing::info_span!(
+            "krabdex.request",
+            method = "GET",
+            path,
+            status = field::Empty,
+            attempt = field::Empty,
+            cache = field::Empty,
+            latency_ms = field::Empty,
+        );
+
+        let started = Instant::now();
+        let mut stats = CallStats::default();
+        let result = self
+            .fetch_json_inner(path, query, target, &mut stats)
+            .instrument(span.clone())
+            .await;
+        let latency = started.elapsed();
+
+        if let Some(status) = stats.status {
+            span.record("status", status);
+        }
+        if let Some(cache_status) = stats.cache_status {
+            span.record("cache", field::debug(cache_status));
+        }
+        span.record("latency_ms", latency.as_millis() as u64);
+
+        if let Some(metrics) = &self.inner.metrics {
+            let endpoint = endpoint_of(path);
+            metrics.on_request(&RequestMetrics {
+                endpoint,
+                status: stats.status,
+                latency,
+                attempts: stats.attempts,
+                bytes_received: stats.bytes_received,
+                cache_status: stats.cache_status,
+            });
+            if let Err(e) = &result {
+                metrics.on_error(endpoint, e);
+            }
         }
 
-        let resp = self.transport.send(req).await?;
+        result
+    }
+
+    async fn fetch_json_inner<T: DeserializeOwned>(
+        &self,
+        path: &str,
+        query: Option<Query>,
+        target: Option<&Target>,
+        stats: &mut CallStats,
+    ) -> Result<Fetched<T>> {
+        let req = self.request(path, query)?;
+        let url_string = req.url.to_string();
+
+        let (resp, cache_status) = self.send(req, stats).await?;
 
         // Non-2xx -> Api error (centralized)
-        if !(200..=299).contains(&resp.status) {
+        if !resp.is_success() {
             let url = path.to_string();
-            return Err(classify_http_error(resp.status, url, &resp));
+            let mut err = classify_http_error(resp.status, url, &resp, target);
+            if let (Some(target), Error::Api(ApiError { kind: ApiErrorKind::NotFound { suggestions, .. }, .. }))
+                = (target, &mut err)
+            {
+                *suggestions = self.suggestions(target).await;
+            }
+            return Err(err);
         }
 
-        serde_json::from_slice::<T>(&resp.body).map_err(|e| Error::Deserialize {
-            url: url_string,
-            source: e,
+        let (value, warnings) = decode::<T>(&resp.body, self.inner.config.deserialize_mode)
+            .map_err(|e| decode_error::<T>(e, url_string))?;
+
+        Ok(Fetched {
+            value,
+            raw: resp.body,
+            headers: resp.headers,
+            status: resp.status,
+            cache_status,
+            warnings,
         })
     }
+
+    /// Send a request that does not target the API (e.g. asset downloads).
+    ///
+    /// Skips the response cache but keeps middleware, retries and `Retry-After` handling.
+    pub(crate) async fn send_uncached(&self, req: HttpRequest) -> Result<HttpResponse> {
+        let span = tracing::info_span!(
+            "krabdex.asset",
+            url = %req.url,
+            status = field::Empty,
+            attempt = field::Empty,
+        );
+        let resp = self
+            .execute(req, &mut CallStats::default())
+            .instrument(span.clone())
+            .await?;
+        span.record("status", resp.status);
+        Ok(resp)
+    }
+
+    /// Build a GET request for `path` with default and per-request headers applied.
+    fn request(&self, path: &str, query: Option<Query>) -> Result<HttpRequest> {
+        let url = join_base(&self.inner.config.base_url, &self.inner.config.api_prefix, path)?;
+
+        let mut req = HttpRequest::new(Method::Get, url);
+        req.headers = self.inner.config.default_headers.clone();
+        for (k, v) in self.options.headers.iter() {
+            req.headers.insert(k, v);
+        }
+        req.timeout = self.options.timeout;
+        if let Some(q) = query {
+            req.query = q;
+        }
+        Ok(req)
+    }
+
+    /// Names similar to a missing identifier, when suggestions are enabled.
+    ///
+    /// Numeric ids get no suggestions, and failing to load the name list is
+    /// not an error: the original `404` is what the caller needs to see.
+    async fn suggestions(&self, target: &Target) -> Vec<String> {
+        if !self.inner.config.not_found_suggestions || target.identifier.parse::<u32>().is_ok() {
+            return Vec::new();
+        }
+        // Boxed: loading the index makes requests whose errors pass through here.
+        match Box::pin(self.name_index(target.resource)).await {
+            Ok(index) => index.suggest(&target.identifier),
+            Err(e) => {
+                tracing::debug!(resource = target.resource, error = %e, "could not load name index");
+                Vec::new()
+            }
+        }
+    }
+
+    /// Walk the list endpoint of `resource` with the largest page size and collect every name.
+    ///
+    /// Each page is an ordinary call, with its own span and metrics; without a
+    /// target, a `404` never asks for suggestions, so this cannot recurse.
+    pub(crate) async fn load_names(&self, resource: &'static str) -> Result<Vec<String>> {
+        let mut names = Vec::new();
+        let mut offset = 0;
+        loop {
+            let page = PageRequest::new(Limit::new(Limit::MAX)?, Offset::new(offset)?);
+            let page: Page<NamedApiResource> =
+                self.fetch(resource, Some(page_query(page)), None).await?.value;
+
+            let received = page.results.len() as u32;
+            names.extend(page.results.into_iter().map(|r| r.name));
+            offset += received;
+            if page.next.is_none() || received == 0 || offset >= page.count {
+                return Ok(names);
+            }
+        }
+    }
+
+    /// The (possibly still empty) index slot for `resource`.
+    pub(crate) fn name_index_cell(&self, resource: &'static str) -> Arc<OnceCell<Arc<NameIndex>>> {
+        let mut indexes = self.inner.name_indexes.lock().unwrap_or_else(|e| e.into_inner());
+        Arc::clone(indexes.entry(resource).or_default())
+    }
+
+    /// Send a request, consulting the response cache when it is enabled.
+    ///
+    /// Expired entries with validators are revalidated; a `304` is turned back
+    /// into the cached response so it never reaches error classification.
+    async fn send(
+        &self,
+        req: HttpRequest,
+        stats: &mut CallStats,
+    ) -> Result<(HttpResponse, CacheStatus)> {
+        let (resp, cache_status) = self.send_cached(req, stats).await?;
+        stats.status = Some(resp.status);
+        stats.cache_status = Some(cache_status);
+        Ok((resp, cache_status))
+    }
+
+    async fn send_cached(
+        &self,
+        req: HttpRequest,
+        stats: &mut CallStats,
+    ) -> Result<(HttpResponse, CacheStatus)> {
+        let mode = self.options.cache_mode;
+        let cache = self.inner.cache.as_ref().filter(|_| mode != CacheMode::Bypass);
+        let Some(cache) = cache else {
+            return Ok((self.execute(req, stats).await?, CacheStatus::Fetched));
+        };
+
+        let key = ResponseCache::key(&req);
+        let mut conditional = req.clone();
+        if mode == CacheMode::Default {
+            match cache.lookup(&key) {
+                Lookup::Fresh(resp) => {
+                    cache.record(CacheStatus::Fresh);
+                    return Ok((resp, CacheStatus::Fresh));
+                }
+                Lookup::Stale(validators) => {
+                    for (k, v) in validators.iter() {
+                        conditional.headers.insert(k, v);
+                    }
+                }
+                Lookup::Miss => {}
+            }
+        }
+
+        let mut resp = self.execute(conditional, stats).await?;
+        if resp.is_not_modified() {
+            if let Some(cached) = cache.revalidate(&key, &resp) {
+                cache.record(CacheStatus::Revalidated);
+                return Ok((cached, CacheStatus::Revalidated));
+            }
+            // Entry was evicted while the request was in flight: fetch it again.
+            resp = self.execute(req, stats).await?;
+        }
+
+        if resp.is_success() {
+            cache.store(key, resp.clone());
+            cache.record(CacheStatus::Fetched);
+        }
+        Ok((resp, CacheStatus::Fetched))
+    }
+
+    /// Call the transport through the middleware stack, retrying retryable transport errors
+    /// and statuses with exponential backoff (or the server's `Retry-After`).
+    async fn execute(&self, req: HttpRequest, stats: &mut CallStats) -> Result<HttpResponse> {
+        let retry = &self.inner.config.retry;
+        let max_retries = if self.options.retries { retry.max_retries } else { 0 };
+        let mut backoff = retry.backoff();
+        let mut attempt = 0;
+
+        loop {
+            stats.attempts += 1;
+            Span::current().record("attempt", stats.attempts);
+            let result = self.inner.middleware.send(&self.inner.transport, req.clone()).await;
+            if let Ok(resp) = &result {
+                stats.bytes_received += resp.body.len() as u64;
+            }
+            let requested_delay = match &result {
+                Err(e @ Error::Transport { .. }) if e.is_retryable() => None,
+                Ok(resp) if is_retryable_status(resp.status) => retry_after(resp),
+                _ => return result,
+            };
+
+            if attempt >= max_retries {
+                return result;
+            }
+            attempt += 1;
+
+            let delay = requested_delay
+                .or_else(|| backoff.next_backoff())
+                .unwrap_or(retry.max_backoff)
+                .min(retry.max_backoff);
+            match &result {
+                Ok(resp) => tracing::warn!(attempt, status = resp.status, ?delay, "retrying request"),
+                Err(e) => tracing::warn!(attempt, error = %e, ?delay, "retrying request"),
+            }
+            tokio::time::sleep(delay).await;
+        }
+    }
+}
+
+/// Turn a decoding failure for `url` into the public error, logging it.
+fn decode_error<T>(e: DecodeError, url: String) -> Error {
+    match e {
+        DecodeError::Json(failure) => {
+            let path = if failure.path.is_empty() { ".".to_string() } else { failure.path };
+            tracing::error!(
+                %url,
+                %path,
+                snippet = failure.snippet.as_deref(),
+                error = %failure.source,
+                "failed to deserialize response"
+            );
+            Error::Deserialize {
+                url,
+                type_name: std::any::type_name::<T>(),
+                path,
+                snippet: failure.snippet,
+                source: failure.source,
+            }
+        }
+        DecodeError::UnknownFields(fields) => {
+            tracing::error!(%url, ?fields, "response has unknown fields");
+            Error::UnknownFields { url, fields }
+        }
+    }
 }
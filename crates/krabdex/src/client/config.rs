@@ -1,5 +1,5 @@
 use ::url::Url;
-use crate::http::Headers;
+use crate::{cache::CacheConfig, client::retry::RetryConfig, decode::DeserializeMode, http::Headers};
 
 /// Configuration values used to construct a `PokeApiClient`.
 #[derive(Debug, Clone)]
@@ -10,6 +10,14 @@ pub struct ClientConfig {
     pub api_prefix: String,
     /// Default headers applied to every request.
     pub default_headers: Headers,
+    /// Response cache settings (disabled when `None`).
+    pub cache: Option<CacheConfig>,
+    /// Retry settings for transient failures.
+    pub retry: RetryConfig,
+    /// How strictly response bodies are matched against the models.
+    pub deserialize_mode: DeserializeMode,
+    /// Attach "did you mean" names to `NotFound` errors for name lookups.
+    pub not_found_suggestions: bool,
 }
 
 impl Default for ClientConfig {
@@ -21,6 +29,10 @@ impl Default for ClientConfig {
             base_url: Url::parse("https://pokeapi.co/").expect("valid default base url"),
             api_prefix: "api/v2".to_string(),
             default_headers: headers,
+            cache: None,
+            retry: RetryConfig::default(),
+            deserialize_mode: DeserializeMode::default(),
+            not_found_suggestions: false,
         }
     }
 }
@@ -0,0 +1,42 @@
+use serde_json::Value;
+
+use crate::{cache::CacheStatus, decode::DeserializeWarning, http::Headers};
+
+/// A typed model together with the exact upstream response it was parsed from.
+///
+/// Useful for archiving raw payloads and diffing them over time, since typed
+/// models drop fields they do not know about.
+#[derive(Debug, Clone)]
+pub struct Fetched<T> {
+    /// The deserialized model.
+    pub value: T,
+    /// Response body bytes exactly as received.
+    pub raw: Vec<u8>,
+    /// Response headers (lowercase names).
+    pub headers: Headers,
+    /// HTTP status of the response (the original status for cached responses).
+    pub status: u16,
+    /// Whether the response came from the cache.
+    pub cache_status: CacheStatus,
+    /// Repairs made in `DeserializeMode::Lenient` (always empty in other modes).
+    pub warnings: Vec<DeserializeWarning>,
+}
+
+impl<T> Fetched<T> {
+    /// Parse the raw body as untyped JSON, including fields the model ignores.
+    pub fn raw_json(&self) -> serde_json::Result<Value> {
+        serde_json::from_slice(&self.raw)
+    }
+
+    /// Transform the typed value, keeping the raw response.
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> Fetched<U> {
+        Fetched {
+            value: f(self.value),
+            raw: self.raw,
+            headers: self.headers,
+            status: self.status,
+            cache_status: self.cache_status,
+            warnings: self.warnings,
+        }
+    }
+}
@@ -1,6 +1,10 @@
 pub mod builder;
 pub mod config;
+#[allow(clippy::module_inception)]
 pub mod client;
+pub mod fetched;
+pub mod options;
 pub mod pagination;
+pub mod retry;
 
 pub use client::PokeApiClient;
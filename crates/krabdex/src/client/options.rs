@@ -0,0 +1,73 @@
+use std::time::Duration;
+
+use crate::{cache::CacheMode, http::Headers};
+
+/// Per-call overrides applied on top of the client configuration.
+///
+/// ```no_run
+/// # async fn run(client: krabdex::PokeApiClient) -> krabdex::Result<()> {
+/// use std::time::Duration;
+/// use krabdex::{cache::CacheMode, client::options::RequestOptions};
+///
+/// let pikachu = client
+///     .with_options(
+///         RequestOptions::new()
+///             .timeout(Duration::from_secs(2))
+///             .header("x-request-id", "abc123")
+///             .cache_mode(CacheMode::ForceRefresh)
+///             .no_retry(),
+///     )
+///     .pokemon_by_id(25)
+///     .await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct RequestOptions {
+    pub(crate) timeout: Option<Duration>,
+    pub(crate) headers: Headers,
+    pub(crate) cache_mode: CacheMode,
+    pub(crate) retries: bool,
+}
+
+impl Default for RequestOptions {
+    fn default() -> Self {
+        Self {
+            timeout: None,
+            headers: Headers::new(),
+            cache_mode: CacheMode::default(),
+            retries: true,
+        }
+    }
+}
+
+impl RequestOptions {
+    /// Options that change nothing (client defaults apply).
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Override the client-wide timeout.
+    pub fn timeout(mut self, d: Duration) -> Self {
+        self.timeout = Some(d);
+        self
+    }
+
+    /// Add a header, overriding a default header with the same name.
+    pub fn header(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.insert(key, value);
+        self
+    }
+
+    /// Choose how the response cache is used.
+    pub fn cache_mode(mut self, mode: CacheMode) -> Self {
+        self.cache_mode = mode;
+        self
+    }
+
+    /// Send the request once, even if it fails with a retryable error.
+    pub fn no_retry(mut self) -> Self {
+        self.retries = false;
+        self
+    }
+}
@@ -0,0 +1,46 @@
+use std::time::Duration;
+
+use backoff::{ExponentialBackoff, ExponentialBackoffBuilder};
+
+use crate::{error::classify::retry_after_secs, http::HttpResponse};
+
+/// Retry settings for transient failures (timeouts, connection errors and retryable statuses).
+#[derive(Debug, Clone)]
+pub struct RetryConfig {
+    /// Number of retries after the first attempt (0, the default, disables retrying).
+    pub max_retries: u32,
+    /// Delay before the first retry; later delays grow exponentially.
+    pub initial_backoff: Duration,
+    /// Upper bound for any single delay, including a server's `Retry-After`.
+    pub max_backoff: Duration,
+}
+
+impl Default for RetryConfig {
+    fn default() -> Self {
+        Self {
+            max_retries: 0,
+            initial_backoff: Duration::from_millis(200),
+            max_backoff: Duration::from_secs(5),
+        }
+    }
+}
+
+impl RetryConfig {
+    pub(crate) fn backoff(&self) -> ExponentialBackoff {
+        ExponentialBackoffBuilder::new()
+            .with_initial_interval(self.initial_backoff)
+            .with_max_interval(self.max_backoff)
+            .with_max_elapsed_time(None)
+            .build()
+    }
+}
+
+/// Statuses worth retrying: the request may succeed if sent again.
+pub(crate) fn is_retryable_status(status: u16) -> bool {
+    matches!(status, 408 | 429 | 500 | 502 | 503 | 504)
+}
+
+/// Delay requested by the server via `Retry-After` (seconds form only).
+pub(crate) fn retry_after(resp: &HttpResponse) -> Option<Duration> {
+    retry_after_secs(resp).map(Duration::from_secs)
+}
@@ -0,0 +1,323 @@
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+use crate::decode::mode::{DeserializeMode, DeserializeWarning};
+
+/// Minimum repair budget in lenient mode; the budget grows with the number of
+/// list items (each may need its own drop), so hostile payloads cannot loop forever.
+const MAX_REPAIRS: usize = 64;
+
+/// Maximum length of the offending-value snippet attached to errors.
+const SNIPPET_LEN: usize = 120;
+
+/// Why a body could not be decoded.
+#[derive(Debug)]
+pub(crate) enum DecodeError {
+    /// The body is not valid JSON for the target type.
+    Json(JsonFailure),
+    /// Strict mode found fields the target type does not know about.
+    UnknownFields(Vec<String>),
+}
+
+/// Where and why JSON decoding failed.
+#[derive(Debug)]
+pub(crate) struct JsonFailure {
+    pub source: serde_json::Error,
+    /// Path of the failure, e.g. `moves[12].version_group_details[0].level_learned_at`.
+    pub path: String,
+    /// Short rendering of the value found at `path`.
+    pub snippet: Option<String>,
+}
+
+impl JsonFailure {
+    /// Locate `path` in the raw body to build the snippet.
+    fn in_body(body: &[u8], path: &[Segment], source: serde_json::Error) -> Self {
+        let snippet = match serde_json::from_slice::<Value>(body) {
+            Ok(doc) => get(&doc, path).map(|v| truncate(&v.to_string())),
+            // Not JSON at all: show the start of the body instead.
+            Err(_) => Some(truncate(&String::from_utf8_lossy(body))),
+        };
+        Self { source, path: format_path(path), snippet }
+    }
+
+    fn in_doc(doc: &Value, path: &[Segment], source: serde_json::Error) -> Self {
+        Self {
+            source,
+            path: format_path(path),
+            snippet: get(doc, path).map(|v| truncate(&v.to_string())),
+        }
+    }
+}
+
+/// Decode a response body according to `mode`.
+pub(crate) fn decode<T: DeserializeOwned>(
+    body: &[u8],
+    mode: DeserializeMode,
+) -> Result<(T, Vec<DeserializeWarning>), DecodeError> {
+    match mode {
+        DeserializeMode::Standard => decode_standard(body).map(|v| (v, Vec::new())),
+        DeserializeMode::Strict => decode_strict(body).map(|v| (v, Vec::new())),
+        DeserializeMode::Lenient => decode_lenient(body),
+    }
+}
+
+fn decode_standard<T: DeserializeOwned>(body: &[u8]) -> Result<T, DecodeError> {
+    let mut de = serde_json::Deserializer::from_slice(body);
+    let value = serde_path_to_error::deserialize(&mut de).map_err(|e| {
+        let path = segments(e.path());
+        DecodeError::Json(JsonFailure::in_body(body, &path, e.into_inner()))
+    })?;
+    de.end().map_err(|e| DecodeError::Json(JsonFailure::in_body(body, &[], e)))?;
+    Ok(value)
+}
+
+fn decode_strict<T: DeserializeOwned>(body: &[u8]) -> Result<T, DecodeError> {
+    let mut unknown = Vec::new();
+    let mut track = serde_path_to_error::Track::new();
+    let mut de = serde_json::Deserializer::from_slice(body);
+
+    let tracked = serde_path_to_error::Deserializer::new(&mut de, &mut track);
+    let value = serde_ignored::deserialize(tracked, |path| unknown.push(format_ignored(&path)))
+        .map_err(|e| {
+            let path = segments(&track.path());
+            DecodeError::Json(JsonFailure::in_body(body, &path, e))
+        })?;
+    de.end().map_err(|e| DecodeError::Json(JsonFailure::in_body(body, &[], e)))?;
+
+    if unknown.is_empty() {
+        Ok(value)
+    } else {
+        Err(DecodeError::UnknownFields(unknown))
+    }
+}
+
+/// Render a `serde_ignored` path as `moves[3].extra`.
+fn format_ignored(path: &serde_ignored::Path<'_>) -> String {
+    match path {
+        serde_ignored::Path::Root => String::new(),
+        serde_ignored::Path::Seq { parent, index } => format!("{}[{index}]", format_ignored(parent)),
+        serde_ignored::Path::Map { parent, key } => {
+            let parent = format_ignored(parent);
+            if parent.is_empty() { key.clone() } else { format!("{parent}.{key}") }
+        }
+        serde_ignored::Path::Some { parent }
+        | serde_ignored::Path::NewtypeStruct { parent }
+        | serde_ignored::Path::NewtypeVariant { parent } => format_ignored(parent),
+    }
+}
+
+/// A step into a JSON document.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Segment {
+    Index(usize),
+    Key(String),
+}
+
+fn decode_lenient<T: DeserializeOwned>(
+    body: &[u8],
+) -> Result<(T, Vec<DeserializeWarning>), DecodeError> {
+    let mut doc: Value = serde_json::from_slice(body)
+        .map_err(|e| DecodeError::Json(JsonFailure::in_body(body, &[], e)))?;
+    let mut warnings = Vec::new();
+
+    for _ in 0..MAX_REPAIRS.max(list_items(&doc)) {
+        let err = match serde_path_to_error::deserialize::<_, T>(&doc) {
+            Ok(value) => return Ok((value, warnings)),
+            Err(err) => err,
+        };
+
+        let path = segments(err.path());
+        let message = err.inner().to_string();
+        if !repair(&mut doc, &path, &message, &mut warnings) {
+            return Err(DecodeError::Json(JsonFailure::in_doc(&doc, &path, err.into_inner())));
+        }
+    }
+
+    serde_path_to_error::deserialize::<_, T>(&doc)
+        .map(|value| (value, warnings))
+        .map_err(|e| {
+            let path = segments(e.path());
+            DecodeError::Json(JsonFailure::in_doc(&doc, &path, e.into_inner()))
+        })
+}
+
+fn segments(path: &serde_path_to_error::Path) -> Vec<Segment> {
+    path.iter()
+        .filter_map(|segment| match segment {
+            serde_path_to_error::Segment::Seq { index } => Some(Segment::Index(*index)),
+            serde_path_to_error::Segment::Map { key } => Some(Segment::Key(key.clone())),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Apply one repair for the error at `path`; returns `false` if nothing could be done.
+///
+/// Field repairs are applied to the same field in every item of the enclosing
+/// lists too: schema drift usually hits all of them, and fixing them one
+/// deserialization at a time would be quadratic.
+fn repair(doc: &mut Value, path: &[Segment], message: &str, warnings: &mut Vec<DeserializeWarning>) -> bool {
+    // Missing field: insert `null`; if the field is not optional, the next
+    // round reports "invalid type: null" and it gets a typed default below.
+    if let Some(field) = missing_field(message) {
+        let mut repaired = false;
+        for at in siblings(doc, path) {
+            if let Some(Value::Object(map)) = get_mut(doc, &at) {
+                if !map.contains_key(field) {
+                    map.insert(field.to_string(), Value::Null);
+                    let mut field_path = at;
+                    field_path.push(Segment::Key(field.to_string()));
+                    warn(warnings, &field_path, format!("{message}; using a default"));
+                    repaired = true;
+                }
+            }
+        }
+        if repaired {
+            return true;
+        }
+    }
+
+    if let Some(default) = null_default(message) {
+        let mut repaired = false;
+        for at in siblings(doc, path) {
+            if let Some(slot @ Value::Null) = get_mut(doc, &at) {
+                *slot = default.clone();
+                // The "missing field" warning already covers defaults we inserted.
+                let formatted = format_path(&at);
+                if !warnings.iter().any(|w| w.path == formatted) {
+                    warn(warnings, &at, format!("{message}; using a default"));
+                }
+                repaired = true;
+            }
+        }
+        if repaired {
+            return true;
+        }
+    }
+
+    // Malformed value inside a list: drop the closest enclosing list item.
+    if let Some(i) = path.iter().rposition(|s| matches!(s, Segment::Index(_))) {
+        let Segment::Index(index) = path[i] else { unreachable!() };
+        if let Some(Value::Array(items)) = get_mut(doc, &path[..i]) {
+            if index < items.len() {
+                items.remove(index);
+                warn(warnings, path, format!("{message}; dropped {}", format_path(&path[..=i])));
+                return true;
+            }
+        }
+    }
+
+    // Malformed value elsewhere: null it (works for optional fields).
+    match get_mut(doc, path) {
+        Some(slot) if !slot.is_null() => {
+            *slot = Value::Null;
+            warn(warnings, path, format!("{message}; set to null"));
+            true
+        }
+        _ => false,
+    }
+}
+
+/// `path` with each list index replaced by every index of that list
+/// (`details[3].level` → `details[0].level`, `details[1].level`, ...).
+fn siblings(doc: &Value, path: &[Segment]) -> Vec<Vec<Segment>> {
+    let mut found = vec![Vec::new()];
+    for segment in path {
+        found = found
+            .into_iter()
+            .flat_map(|prefix| {
+                let len = match segment {
+                    Segment::Index(_) => get(doc, &prefix).and_then(Value::as_array).map_or(0, Vec::len),
+                    Segment::Key(_) => 1,
+                };
+                (0..len).map(move |i| {
+                    let mut at = prefix.clone();
+                    at.push(match segment {
+                        Segment::Index(_) => Segment::Index(i),
+                        Segment::Key(k) => Segment::Key(k.clone()),
+                    });
+                    at
+                })
+            })
+            .collect();
+    }
+    found
+}
+
+/// Number of list items anywhere in `doc`.
+fn list_items(doc: &Value) -> usize {
+    match doc {
+        Value::Array(items) => items.len() + items.iter().map(list_items).sum::<usize>(),
+        Value::Object(map) => map.values().map(list_items).sum(),
+        _ => 0,
+    }
+}
+
+fn warn(warnings: &mut Vec<DeserializeWarning>, path: &[Segment], message: String) {
+    let path = format_path(path);
+    tracing::warn!(%path, %message, "lenient deserialization repaired response");
+    warnings.push(DeserializeWarning { path, message });
+}
+
+/// Field name from serde's "missing field `name`" message.
+fn missing_field(message: &str) -> Option<&str> {
+    message.strip_prefix("missing field `")?.split('`').next()
+}
+
+/// Default value for a non-optional field that came back `null`.
+fn null_default(message: &str) -> Option<Value> {
+    let expected = message.strip_prefix("invalid type: null, expected ")?;
+    let default = if expected.contains("sequence") {
+        Value::Array(Vec::new())
+    } else if expected.contains("string") {
+        Value::String(String::new())
+    } else if expected.contains("bool") {
+        Value::Bool(false)
+    } else if expected.starts_with('u') || expected.starts_with('i') || expected.starts_with('f') {
+        Value::from(0)
+    } else if expected.contains("struct") || expected.contains("map") {
+        Value::Object(Default::default())
+    } else {
+        return None;
+    };
+    Some(default)
+}
+
+fn get<'a>(doc: &'a Value, path: &[Segment]) -> Option<&'a Value> {
+    path.iter().try_fold(doc, |value, segment| match segment {
+        Segment::Index(i) => value.get(*i),
+        Segment::Key(k) => value.get(k.as_str()),
+    })
+}
+
+fn get_mut<'a>(doc: &'a mut Value, path: &[Segment]) -> Option<&'a mut Value> {
+    path.iter().try_fold(doc, |value, segment| match segment {
+        Segment::Index(i) => value.get_mut(*i),
+        Segment::Key(k) => value.get_mut(k.as_str()),
+    })
+}
+
+fn format_path(path: &[Segment]) -> String {
+    let mut out = String::new();
+    for segment in path {
+        match segment {
+            Segment::Index(i) => out.push_str(&format!("[{i}]")),
+            Segment::Key(k) => {
+                if !out.is_empty() {
+                    out.push('.');
+                }
+                out.push_str(k);
+            }
+        }
+    }
+    out
+}
+
+fn truncate(s: &str) -> String {
+    if s.chars().count() <= SNIPPET_LEN {
+        return s.to_string();
+    }
+    let mut out: String = s.chars().take(SNIPPET_LEN).collect();
+    out.push('…');
+    out
+}
@@ -0,0 +1,7 @@
+pub mod mode;
+pub(crate) mod decoder;
+
+pub use mode::{DeserializeMode, DeserializeWarning};
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,31 @@
+use std::fmt;
+
+/// How strictly response bodies are matched against the typed models.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DeserializeMode {
+    /// Ignore unknown fields; fail on missing or malformed ones.
+    #[default]
+    Standard,
+    /// Fail with `Error::UnknownFields` when the response has fields the model
+    /// does not know about. Intended for contract tests against upstream.
+    Strict,
+    /// Repair what cannot be parsed instead of failing: missing fields get
+    /// defaults, malformed values are nulled and malformed list items dropped.
+    /// Every repair is reported as a `DeserializeWarning`.
+    Lenient,
+}
+
+/// Something lenient deserialization had to repair.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeserializeWarning {
+    /// Location of the repaired value, e.g. `moves[12].version_group_details[0]`.
+    pub path: String,
+    /// What was wrong and how it was repaired.
+    pub message: String,
+}
+
+impl fmt::Display for DeserializeWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.path, self.message)
+    }
+}
@@ -0,0 +1,161 @@
+use serde::Deserialize;
+
+use crate::decode::{
+    decoder::{decode, DecodeError},
+    mode::DeserializeMode,
+};
+
+#[derive(Debug, Deserialize, PartialEq)]
+struct Detail {
+    level: u32,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+struct Mon {
+    name: String,
+    order: u32,
+    nickname: Option<String>,
+    details: Vec<Detail>,
+    extra: Option<Vec<u32>>,
+}
+
+#[test]
+fn standard_ignores_unknown_fields() {
+    let body = br#"{"name":"a","order":1,"details":[],"new_field":1}"#;
+    let (mon, warnings) = decode::<Mon>(body, DeserializeMode::Standard).unwrap();
+
+    assert_eq!(mon.name, "a");
+    assert!(warnings.is_empty());
+}
+
+#[test]
+fn strict_reports_every_unknown_field() {
+    let body = br#"{"name":"a","order":1,"details":[{"level":1,"how":"egg"}],"new_field":1}"#;
+
+    match decode::<Mon>(body, DeserializeMode::Strict) {
+        Err(DecodeError::UnknownFields(fields)) => {
+            assert_eq!(fields, vec!["details[0].how".to_string(), "new_field".to_string()]);
+        }
+        other => panic!("expected unknown fields, got {other:?}"),
+    }
+}
+
+#[test]
+fn strict_accepts_exact_payloads() {
+    let body = br#"{"name":"a","order":1,"nickname":null,"details":[],"extra":[1]}"#;
+    assert!(decode::<Mon>(body, DeserializeMode::Strict).is_ok());
+}
+
+#[test]
+fn lenient_defaults_missing_fields() {
+    let body = br#"{"name":"a"}"#;
+    let (mon, warnings) = decode::<Mon>(body, DeserializeMode::Lenient).unwrap();
+
+    assert_eq!(mon.order, 0);
+    assert!(mon.details.is_empty());
+    let paths: Vec<_> = warnings.iter().map(|w| w.path.as_str()).collect();
+    assert_eq!(paths, vec!["order", "details"]);
+}
+
+#[test]
+fn lenient_drops_malformed_list_items() {
+    let body = br#"{"name":"a","order":1,"details":[{"level":1},{"level":"high"},{"level":3}]}"#;
+    let (mon, warnings) = decode::<Mon>(body, DeserializeMode::Lenient).unwrap();
+
+    assert_eq!(mon.details, vec![Detail { level: 1 }, Detail { level: 3 }]);
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(warnings[0].path, "details[1].level");
+    assert!(warnings[0].message.contains("dropped details[1]"), "{}", warnings[0]);
+}
+
+#[test]
+fn lenient_repairs_drift_in_every_list_item() {
+    #[derive(Debug, Deserialize)]
+    struct Move {
+        details: Vec<Detail>,
+    }
+
+    // `level` renamed upstream in all 100 × 3 entries.
+    let detail = serde_json::json!({ "level_learned_at": 5 });
+    let moves: Vec<_> = (0..100).map(|_| serde_json::json!({ "details": [detail, detail, detail] })).collect();
+    let body = serde_json::to_vec(&moves).unwrap();
+
+    let (moves, warnings) = decode::<Vec<Move>>(&body, DeserializeMode::Lenient).unwrap();
+
+    assert_eq!(moves.len(), 100);
+    assert!(moves.iter().flat_map(|m| &m.details).all(|d| d.level == 0));
+    assert_eq!(warnings.len(), 300);
+    assert_eq!(warnings[299].path, "[99].details[2].level");
+}
+
+#[test]
+fn lenient_drops_more_malformed_items_than_the_minimum_budget() {
+    let details: Vec<_> = (0..100).map(|i| serde_json::json!({ "level": if i % 2 == 0 { "high".into() } else { serde_json::json!(i) } })).collect();
+    let body = serde_json::to_vec(&serde_json::json!({ "name": "a", "order": 1, "details": details })).unwrap();
+
+    let (mon, warnings) = decode::<Mon>(&body, DeserializeMode::Lenient).unwrap();
+
+    assert_eq!(mon.details.len(), 50);
+    assert_eq!(warnings.len(), 50);
+}
+
+#[test]
+fn lenient_nulls_malformed_optional_values() {
+    let body = br#"{"name":"a","order":1,"details":[],"extra":"nope"}"#;
+    let (mon, warnings) = decode::<Mon>(body, DeserializeMode::Lenient).unwrap();
+
+    assert_eq!(mon.extra, None);
+    assert_eq!(warnings[0].path, "extra");
+}
+
+#[test]
+fn lenient_still_fails_on_invalid_json() {
+    assert!(matches!(
+        decode::<Mon>(b"not json", DeserializeMode::Lenient),
+        Err(DecodeError::Json(_))
+    ));
+}
+
+#[test]
+fn failures_report_path_and_snippet() {
+    let body = br#"{"name":"a","order":1,"details":[{"level":1},{"level":"high"}]}"#;
+
+    for mode in [DeserializeMode::Standard, DeserializeMode::Strict] {
+        match decode::<Mon>(body, mode) {
+            Err(DecodeError::Json(failure)) => {
+                assert_eq!(failure.path, "details[1].level");
+                assert_eq!(failure.snippet.as_deref(), Some("\"high\""));
+                assert!(failure.source.to_string().contains("expected u32"));
+            }
+            other => panic!("expected json failure in {mode:?}, got {other:?}"),
+        }
+    }
+}
+
+#[test]
+fn missing_fields_point_at_the_parent_object() {
+    let body = br#"{"name":"a","order":1,"details":[{}]}"#;
+
+    match decode::<Mon>(body, DeserializeMode::Standard) {
+        Err(DecodeError::Json(failure)) => {
+            assert_eq!(failure.path, "details[0]");
+            assert_eq!(failure.snippet.as_deref(), Some("{}"));
+        }
+        other => panic!("expected json failure, got {other:?}"),
+    }
+}
+
+#[test]
+fn long_snippets_are_truncated() {
+    let long = "x".repeat(500);
+    let body = format!(r#"{{"name":"a","order":"{long}","details":[]}}"#);
+
+    match decode::<Mon>(body.as_bytes(), DeserializeMode::Standard) {
+        Err(DecodeError::Json(failure)) => {
+            let snippet = failure.snippet.unwrap();
+            assert_eq!(snippet.chars().count(), 121);
+            assert!(snippet.ends_with('…'));
+        }
+        other => panic!("expected json failure, got {other:?}"),
+    }
+}
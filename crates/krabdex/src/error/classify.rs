@@ -1,31 +1,57 @@
+use crate::client::retry::is_retryable_status;
 use crate::error::{ApiError, ApiErrorKind, Error};
 use crate::http::HttpResponse;
 
+/// The resource a typed API method asked for, used to describe `404`s.
+#[derive(Debug, Clone)]
+pub(crate) struct Target {
+    pub(crate) resource: &'static str,
+    pub(crate) identifier: String,
+}
+
+impl Target {
+    pub(crate) fn new(resource: &'static str, identifier: impl ToString) -> Self {
+        Self {
+            resource,
+            identifier: identifier.to_string(),
+        }
+    }
+
+    /// Path of the resource relative to the API prefix.
+    pub(crate) fn path(&self) -> String {
+        format!("{}/{}", self.resource, self.identifier)
+    }
+}
+
 pub fn classify_http_error(
     status: u16,
     url: String,
     resp: &HttpResponse,
+    target: Option<&Target>,
 ) -> Error {
     match status {
-        404 => Error::Api(ApiError {
-            status,
-            url,
-            kind: ApiErrorKind::NotFound {
-                resource: "resource",
-                identifier: "<unknown>".into(),
-            },
-        }),
+        404 => {
+            let (resource, identifier) = match target {
+                Some(t) => (t.resource, t.identifier.clone()),
+                None => ("resource", "<unknown>".into()),
+            };
 
-        429 => {
-            let retry_after = resp
-                .headers
-                .get("retry-after")
-                .and_then(|v| v.parse().ok());
+            Error::Api(ApiError {
+                status,
+                url,
+                kind: ApiErrorKind::NotFound {
+                    resource,
+                    identifier,
+                    suggestions: Vec::new(),
+                },
+            })
+        }
 
+        429 => {
             Error::Api(ApiError {
                 status,
                 url,
-                kind: ApiErrorKind::RateLimited { retry_after },
+                kind: ApiErrorKind::RateLimited { retry_after: retry_after_secs(resp) },
             })
         }
 
@@ -44,8 +70,16 @@ pub fn classify_http_error(
             Error::Api(ApiError {
                 status,
                 url,
-                kind: ApiErrorKind::HttpStatus { body_snippet: snippet },
+                kind: ApiErrorKind::HttpStatus {
+                    body_snippet: snippet,
+                    retry_after: retry_after_secs(resp).filter(|_| is_retryable_status(status)),
+                },
             })
         }
     }
 }
+
+/// `Retry-After` header of a response, in seconds (the HTTP-date form is ignored).
+pub(crate) fn retry_after_secs(resp: &HttpResponse) -> Option<u64> {
+    resp.headers.get("retry-after").and_then(|v| v.trim().parse().ok())
+}
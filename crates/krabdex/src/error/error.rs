@@ -1,5 +1,7 @@
 use thiserror::Error;
-use std::{error::Error as StdError, fmt};
+use std::{error::Error as StdError, fmt, path::PathBuf, time::Duration};
+
+use crate::client::retry::is_retryable_status;
 
 /// Convenience result type used throughout the SDK.
 pub type Result<T> = std::result::Result<T, Error>;
@@ -12,8 +14,9 @@ pub enum Error {
     Internal(&'static str),
     
     /// Networking error (TLS, DNS, IO, etc).
-    #[error("transport error")]
+    #[error("transport error ({kind})")]
     Transport {
+        kind: TransportErrorKind,
         #[source]
         source: Box<dyn StdError + Send + Sync>,
     },
@@ -23,13 +26,51 @@ pub enum Error {
     Api(ApiError),
 
     /// Response body could not be deserialized.
-    #[error("failed to deserialize response from {url}")]
+    #[error("failed to deserialize {type_name} from {url} at `{path}`")]
     Deserialize {
         url: String,
+        /// Rust type the body was decoded into.
+        type_name: &'static str,
+        /// JSON path of the failure, e.g. `moves[12].version_group_details[0].level_learned_at`
+        /// (`.` for the document root).
+        path: String,
+        /// Short rendering of the offending value, if it could be located.
+        snippet: Option<String>,
         #[source]
         source: serde_json::Error,
     },
 
+    /// Response contained fields the models do not know about
+    /// (only raised in `DeserializeMode::Strict`).
+    #[error("response from {url} has unknown fields: {}", fields.join(", "))]
+    UnknownFields {
+        url: String,
+        fields: Vec<String>,
+    },
+
+    /// A downloaded asset was not an image or audio file of the expected kind.
+    #[error("unexpected content type {} for asset {url}", content_type.as_deref().unwrap_or("<none>"))]
+    UnexpectedContentType {
+        url: String,
+        content_type: Option<String>,
+    },
+
+    /// Reading or writing the local asset store failed.
+    #[error("asset storage error at {}", path.display())]
+    Storage {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+
+    /// Client setup failed outside the network layer (e.g. the blocking client's runtime).
+    #[error("failed to build {component}")]
+    Build {
+        component: &'static str,
+        #[source]
+        source: std::io::Error,
+    },
+
     /// User provided invalid input (local validation failure).
     #[error("invalid argument `{field}`: {reason}")]
     InvalidArgument {
@@ -38,6 +79,108 @@ pub enum Error {
     },
 }
 
+/// What went wrong below the HTTP layer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransportErrorKind {
+    /// The request or response did not complete within the configured timeout.
+    Timeout,
+    /// The connection could not be established (DNS, refused, reset).
+    Connect,
+    /// TLS handshake or certificate validation failed.
+    Tls,
+    /// Anything else (body read errors, HTTP client construction); not retried.
+    Other,
+}
+
+impl fmt::Display for TransportErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Timeout => "timeout",
+            Self::Connect => "connect",
+            Self::Tls => "tls",
+            Self::Other => "other",
+        })
+    }
+}
+
+impl Error {
+    /// HTTP status of an API error response.
+    pub fn status(&self) -> Option<u16> {
+        match self {
+            Self::Api(api) => Some(api.status),
+            _ => None,
+        }
+    }
+
+    /// The resource does not exist (HTTP 404).
+    pub fn is_not_found(&self) -> bool {
+        matches!(self, Self::Api(ApiError { kind: ApiErrorKind::NotFound { .. }, .. }))
+    }
+
+    /// The API rejected the request because of rate limiting (HTTP 429).
+    pub fn is_rate_limited(&self) -> bool {
+        matches!(self, Self::Api(ApiError { kind: ApiErrorKind::RateLimited { .. }, .. }))
+    }
+
+    /// Sending the same request again may succeed.
+    ///
+    /// True for timeouts, connection failures and transient HTTP statuses
+    /// (408, 429, 500, 502, 503, 504); false for TLS and other transport
+    /// failures, and for bad input.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            Self::Transport { kind, .. } => {
+                matches!(kind, TransportErrorKind::Timeout | TransportErrorKind::Connect)
+            }
+            Self::Api(api) => is_retryable_status(api.status),
+            _ => false,
+        }
+    }
+
+    /// Delay the server asked for before retrying, if it sent one with a
+    /// retryable status (429, 503, ...).
+    pub fn retry_after(&self) -> Option<Duration> {
+        match self {
+            Self::Api(ApiError {
+                kind:
+                    ApiErrorKind::RateLimited { retry_after }
+                    | ApiErrorKind::HttpStatus { retry_after, .. },
+                ..
+            }) => retry_after.map(Duration::from_secs),
+            _ => None,
+        }
+    }
+
+    /// URL (or API path) of the request that failed, when known.
+    pub fn url(&self) -> Option<&str> {
+        match self {
+            Self::Api(api) => Some(&api.url),
+            Self::Deserialize { url, .. }
+            | Self::UnknownFields { url, .. }
+            | Self::UnexpectedContentType { url, .. } => Some(url),
+            _ => None,
+        }
+    }
+
+    /// Kind of transport failure, for `Error::Transport`.
+    pub fn transport_kind(&self) -> Option<TransportErrorKind> {
+        match self {
+            Self::Transport { kind, .. } => Some(*kind),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn transport(
+        kind: TransportErrorKind,
+        source: impl Into<Box<dyn StdError + Send + Sync>>,
+    ) -> Self {
+        Self::Transport {
+            kind,
+            source: source.into(),
+        }
+    }
+}
+
 /// Details of an API error response.
 #[derive(Debug)]
 pub struct ApiError {
@@ -52,24 +195,33 @@ pub enum ApiErrorKind {
     NotFound {
         resource: &'static str,
         identifier: String,
+        /// Similar existing names (only filled when `not_found_suggestions` is enabled).
+        suggestions: Vec<String>,
     },
     RateLimited {
         retry_after: Option<u64>,
     },
     HttpStatus {
         body_snippet: Option<String>,
+        /// `Retry-After` in seconds, kept for retryable statuses (e.g. a 503 during maintenance).
+        retry_after: Option<u64>,
     },
 }
 
 impl fmt::Display for ApiError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match &self.kind {
-            ApiErrorKind::NotFound { resource, identifier } => {
+            ApiErrorKind::NotFound { resource, identifier, suggestions } => {
                 write!(
                     f,
                     "{} `{}` not found (status {})",
                     resource, identifier, self.status
-                )
+                )?;
+                if !suggestions.is_empty() {
+                    let names: Vec<String> = suggestions.iter().map(|s| format!("`{s}`")).collect();
+                    write!(f, "; did you mean {}?", names.join(", "))?;
+                }
+                Ok(())
             }
             ApiErrorKind::RateLimited { retry_after } => {
                 if let Some(secs) = retry_after {
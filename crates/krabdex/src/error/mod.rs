@@ -1,7 +1,10 @@
+#[allow(clippy::module_inception)]
 pub mod error;
 pub(crate) mod classify;
+pub mod report;
 
-pub use error::{Error, ApiError, ApiErrorKind, Result};
+pub use error::{Error, ApiError, ApiErrorKind, Result, TransportErrorKind};
+pub use report::{ErrorCode, ErrorReport};
 
 #[cfg(test)]
 mod tests;
@@ -0,0 +1,138 @@
+use std::error::Error as StdError;
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::{ApiError, ApiErrorKind, Error, TransportErrorKind};
+
+/// Stable, machine-readable category of an `Error`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ErrorCode {
+    Internal,
+    Timeout,
+    Connect,
+    Tls,
+    Transport,
+    NotFound,
+    RateLimited,
+    HttpStatus,
+    Deserialize,
+    UnknownFields,
+    UnexpectedContentType,
+    Storage,
+    Build,
+    InvalidArgument,
+}
+
+impl ErrorCode {
+    /// The code as it appears in serialized reports (e.g. `"not_found"`).
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Internal => "internal",
+            Self::Timeout => "timeout",
+            Self::Connect => "connect",
+            Self::Tls => "tls",
+            Self::Transport => "transport",
+            Self::NotFound => "not_found",
+            Self::RateLimited => "rate_limited",
+            Self::HttpStatus => "http_status",
+            Self::Deserialize => "deserialize",
+            Self::UnknownFields => "unknown_fields",
+            Self::UnexpectedContentType => "unexpected_content_type",
+            Self::Storage => "storage",
+            Self::Build => "build",
+            Self::InvalidArgument => "invalid_argument",
+        }
+    }
+}
+
+/// Serializable snapshot of an `Error`, for JSON APIs, logs and failed batch items.
+///
+/// Unlike `Error` it is `Clone` and round-trips through serde; optional fields
+/// are omitted from the JSON when they do not apply.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ErrorReport {
+    pub code: ErrorCode,
+    /// Human-readable message, including the chain of underlying causes.
+    pub message: String,
+    /// Whether sending the same request again may succeed.
+    pub retryable: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<u16>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    /// Seconds the server asked callers to wait before retrying.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub retry_after: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub resource: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub identifier: Option<String>,
+}
+
+impl Error {
+    /// Stable error category, as used in `ErrorReport`.
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            Self::Internal(_) => ErrorCode::Internal,
+            Self::Transport { kind, .. } => match kind {
+                TransportErrorKind::Timeout => ErrorCode::Timeout,
+                TransportErrorKind::Connect => ErrorCode::Connect,
+                TransportErrorKind::Tls => ErrorCode::Tls,
+                TransportErrorKind::Other => ErrorCode::Transport,
+            },
+            Self::Api(api) => match api.kind {
+                ApiErrorKind::NotFound { .. } => ErrorCode::NotFound,
+                ApiErrorKind::RateLimited { .. } => ErrorCode::RateLimited,
+                ApiErrorKind::HttpStatus { .. } => ErrorCode::HttpStatus,
+            },
+            Self::Deserialize { .. } => ErrorCode::Deserialize,
+            Self::UnknownFields { .. } => ErrorCode::UnknownFields,
+            Self::UnexpectedContentType { .. } => ErrorCode::UnexpectedContentType,
+            Self::Storage { .. } => ErrorCode::Storage,
+            Self::Build { .. } => ErrorCode::Build,
+            Self::InvalidArgument { .. } => ErrorCode::InvalidArgument,
+        }
+    }
+
+    /// Serializable snapshot of this error.
+    pub fn report(&self) -> ErrorReport {
+        ErrorReport::from(self)
+    }
+}
+
+impl From<&Error> for ErrorReport {
+    fn from(e: &Error) -> Self {
+        let mut message = e.to_string();
+        let mut source = e.source();
+        while let Some(cause) = source {
+            message.push_str(": ");
+            message.push_str(&cause.to_string());
+            source = cause.source();
+        }
+
+        let (resource, identifier) = match e {
+            Error::Api(ApiError { kind: ApiErrorKind::NotFound { resource, identifier, .. }, .. }) => {
+                (Some(resource.to_string()), Some(identifier.clone()))
+            }
+            _ => (None, None),
+        };
+
+        Self {
+            code: e.code(),
+            message,
+            retryable: e.is_retryable(),
+            status: e.status(),
+            url: e.url().map(str::to_string),
+            retry_after: e.retry_after().map(|d| d.as_secs()),
+            resource,
+            identifier,
+        }
+    }
+}
+
+impl From<Error> for ErrorReport {
+    fn from(e: Error) -> Self {
+        Self::from(&e)
+    }
+}
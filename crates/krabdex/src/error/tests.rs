@@ -16,7 +16,7 @@ fn make_resp(status: u16, body: &[u8], headers: &[(&str, &str)]) -> HttpResponse
 #[test]
 fn classify_404_returns_not_found() {
     let resp = make_resp(404, b"", &[]);
-    let err = classify::classify_http_error(404, "pokemon/ghost".into(), &resp);
+    let err = classify::classify_http_error(404, "pokemon/ghost".into(), &resp, None);
 
     match err {
         Error::Api(api) => match api.kind {
@@ -27,10 +27,48 @@ fn classify_404_returns_not_found() {
     }
 }
 
+#[test]
+fn classify_404_uses_target() {
+    let resp = make_resp(404, b"Not Found", &[]);
+    let target = classify::Target::new("pokemon", "pikachuu");
+    let err = classify::classify_http_error(404, "pokemon/pikachuu".into(), &resp, Some(&target));
+
+    match &err {
+        Error::Api(api) => match &api.kind {
+            ApiErrorKind::NotFound { resource, identifier, suggestions } => {
+                assert_eq!(*resource, "pokemon");
+                assert_eq!(identifier, "pikachuu");
+                assert!(suggestions.is_empty());
+            }
+            other => panic!("unexpected api kind: {other:?}"),
+        },
+        other => panic!("unexpected error: {other:?}"),
+    }
+    assert_eq!(err.to_string(), "api error: pokemon `pikachuu` not found (status 404)");
+}
+
+#[test]
+fn not_found_display_lists_suggestions() {
+    let err = ApiError {
+        status: 404,
+        url: "pokemon/pikachuu".into(),
+        kind: ApiErrorKind::NotFound {
+            resource: "pokemon",
+            identifier: "pikachuu".into(),
+            suggestions: vec!["pikachu".into(), "pichu".into()],
+        },
+    };
+
+    assert_eq!(
+        err.to_string(),
+        "pokemon `pikachuu` not found (status 404); did you mean `pikachu`, `pichu`?"
+    );
+}
+
 #[test]
 fn classify_429_extracts_retry_after() {
     let resp = make_resp(429, b"", &[("retry-after", "5")]);
-    let err = classify::classify_http_error(429, "pokemon/overload".into(), &resp);
+    let err = classify::classify_http_error(429, "pokemon/overload".into(), &resp, None);
 
     match err {
         Error::Api(api) => match api.kind {
@@ -44,13 +82,131 @@ fn classify_429_extracts_retry_after() {
 #[test]
 fn classify_generic_includes_snippet() {
     let resp = make_resp(500, b"oops", &[]);
-    let err = classify::classify_http_error(500, "pokemon/boom".into(), &resp);
+    let err = classify::classify_http_error(500, "pokemon/boom".into(), &resp, None);
 
     match err {
         Error::Api(api) => match api.kind {
-            ApiErrorKind::HttpStatus { body_snippet } => assert_eq!(body_snippet.as_deref(), Some("oops")),
+            ApiErrorKind::HttpStatus { body_snippet, .. } => assert_eq!(body_snippet.as_deref(), Some("oops")),
             other => panic!("unexpected api kind: {other:?}"),
         },
         other => panic!("unexpected error: {other:?}"),
     }
 }
+
+#[test]
+fn helpers_classify_api_errors() {
+    let not_found = classify::classify_http_error(404, "pokemon/ghost".into(), &make_resp(404, b"", &[]), None);
+    assert!(not_found.is_not_found());
+    assert!(!not_found.is_retryable());
+    assert_eq!(not_found.status(), Some(404));
+    assert_eq!(not_found.url(), Some("pokemon/ghost"));
+
+    let limited = classify::classify_http_error(
+        429,
+        "pokemon/overload".into(),
+        &make_resp(429, b"", &[("retry-after", "7")]),
+        None,
+    );
+    assert!(limited.is_rate_limited());
+    assert!(limited.is_retryable());
+    assert_eq!(limited.retry_after(), Some(std::time::Duration::from_secs(7)));
+
+    let unavailable = classify::classify_http_error(503, "pokemon/1".into(), &make_resp(503, b"", &[]), None);
+    assert!(unavailable.is_retryable());
+    assert!(!unavailable.is_not_found());
+    assert_eq!(unavailable.retry_after(), None);
+
+    let maintenance = classify::classify_http_error(
+        503,
+        "pokemon/1".into(),
+        &make_resp(503, b"", &[("retry-after", "30")]),
+        None,
+    );
+    assert_eq!(maintenance.retry_after(), Some(std::time::Duration::from_secs(30)));
+
+    let bad_request = classify::classify_http_error(
+        400,
+        "pokemon/1".into(),
+        &make_resp(400, b"", &[("retry-after", "30")]),
+        None,
+    );
+    assert_eq!(bad_request.retry_after(), None);
+}
+
+#[test]
+fn helpers_classify_transport_and_local_errors() {
+    let io = || std::io::Error::other("boom");
+
+    let timeout = Error::transport(TransportErrorKind::Timeout, io());
+    assert!(timeout.is_retryable());
+    assert_eq!(timeout.transport_kind(), Some(TransportErrorKind::Timeout));
+    assert_eq!(timeout.status(), None);
+    assert_eq!(timeout.to_string(), "transport error (timeout)");
+
+    let tls = Error::transport(TransportErrorKind::Tls, io());
+    assert!(!tls.is_retryable());
+
+    let other = Error::transport(TransportErrorKind::Other, io());
+    assert!(!other.is_retryable());
+
+    let invalid = Error::InvalidArgument { field: "name", reason: "empty".into() };
+    assert!(!invalid.is_retryable());
+    assert_eq!(invalid.url(), None);
+    assert_eq!(invalid.transport_kind(), None);
+
+    let build = Error::Build { component: "tokio runtime", source: io() };
+    assert!(!build.is_retryable());
+    assert_eq!(build.code(), ErrorCode::Build);
+    assert_eq!(build.to_string(), "failed to build tokio runtime");
+}
+
+#[test]
+fn report_captures_not_found_details() {
+    let resp = make_resp(404, b"", &[]);
+    let target = classify::Target::new("pokemon", "pikachuu");
+    let err = classify::classify_http_error(404, "pokemon/pikachuu".into(), &resp, Some(&target));
+
+    let report = err.report();
+    assert_eq!(report.code, ErrorCode::NotFound);
+    assert_eq!(report.status, Some(404));
+    assert_eq!(report.resource.as_deref(), Some("pokemon"));
+    assert_eq!(report.identifier.as_deref(), Some("pikachuu"));
+    assert!(!report.retryable);
+
+    let json = serde_json::to_value(&report).unwrap();
+    assert_eq!(
+        json,
+        serde_json::json!({
+            "code": "not_found",
+            "message": "api error: pokemon `pikachuu` not found (status 404)",
+            "retryable": false,
+            "status": 404,
+            "url": "pokemon/pikachuu",
+            "resource": "pokemon",
+            "identifier": "pikachuu",
+        })
+    );
+    let back: ErrorReport = serde_json::from_value(json).unwrap();
+    assert_eq!(back, report);
+}
+
+#[test]
+fn report_includes_cause_chain_and_retry_after() {
+    let err = Error::transport(TransportErrorKind::Timeout, std::io::Error::other("deadline elapsed"));
+    let report = ErrorReport::from(err);
+    assert_eq!(report.code, ErrorCode::Timeout);
+    assert_eq!(report.message, "transport error (timeout): deadline elapsed");
+    assert!(report.retryable);
+    assert_eq!(report.url, None);
+
+    let limited = classify::classify_http_error(
+        429,
+        "pokemon".into(),
+        &make_resp(429, b"", &[("retry-after", "3")]),
+        None,
+    );
+    let json = serde_json::to_value(limited.report()).unwrap();
+    assert_eq!(json["code"], "rate_limited");
+    assert_eq!(json["retry_after"], 3);
+    assert!(json.get("resource").is_none());
+}
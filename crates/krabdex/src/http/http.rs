@@ -1,11 +1,13 @@
-use std::collections::BTreeMap;
+use std::{collections::BTreeMap, time::Duration};
 use ::url::Url;
 
+/// HTTP method of a request.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Method {
     Get
 }
 
+/// Header map (names are stored as given; responses use lowercase names).
 #[derive(Debug, Clone, Default)]
 pub struct Headers(BTreeMap<String, String>);
 
@@ -20,11 +22,16 @@ impl Headers {
         self.0.get(key).map(|s| s.as_str())
     }
 
+    pub fn remove(&mut self, key: &str) -> Option<String> {
+        self.0.remove(key)
+    }
+
     pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
         self.0.iter().map(|(k, v)| (k.as_str(), v.as_str()))
     }
 }
 
+/// Query string parameters.
 #[derive(Debug, Clone, Default)]
 pub struct Query(BTreeMap<String, String>);
 
@@ -40,12 +47,15 @@ impl Query {
     }
 }
 
+/// Outgoing request handed to the transport.
 #[derive(Debug, Clone)]
 pub struct HttpRequest {
     pub method: Method,
     pub url: Url,
     pub headers: Headers,
     pub query: Query,
+    /// Overrides the transport's default timeout for this request.
+    pub timeout: Option<Duration>,
 }
 
 impl HttpRequest {
@@ -55,10 +65,12 @@ impl HttpRequest {
             url,
             headers: Headers::new(),
             query: Query::new(),
+            timeout: None,
         }
     }
 }
 
+/// Response received from the transport.
 #[derive(Debug, Clone)]
 pub struct HttpResponse {
     pub status: u16,
@@ -67,4 +79,13 @@ pub struct HttpResponse {
 }
 
 impl HttpResponse {
+    /// Whether the status is in the 2xx range.
+    pub fn is_success(&self) -> bool {
+        (200..=299).contains(&self.status)
+    }
+
+    /// Whether the server answered a conditional request with `304 Not Modified`.
+    pub fn is_not_modified(&self) -> bool {
+        self.status == 304
+    }
 }
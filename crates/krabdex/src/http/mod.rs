@@ -1,7 +1,8 @@
+#[allow(clippy::module_inception)]
 pub(crate) mod http;
 pub(crate) mod url;
 
-pub(crate) use http::*;
+pub use http::*;
 
 #[cfg(test)]
 mod tests;
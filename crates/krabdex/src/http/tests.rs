@@ -69,3 +69,34 @@ fn http_request_defaults_to_empty_headers_and_query() {
     assert_eq!(req.headers.iter().count(), 0);
     assert_eq!(req.query.iter().count(), 0);
 }
+
+#[test]
+fn resource_path_splits_kind_and_identifier() {
+    use crate::http::url::resource_path;
+
+    let base = Url::parse("https://pokeapi.co/").unwrap();
+    let (kind, id) = resource_path(&base, "api/v2", "https://pokeapi.co/api/v2/pokemon/25/").unwrap();
+    assert_eq!((kind.as_str(), id.as_str()), ("pokemon", "25"));
+
+    let mirror = Url::parse("http://localhost:8000/pokeapi/").unwrap();
+    let (kind, id) = resource_path(&mirror, "/api/v2/", "http://localhost:8000/pokeapi/api/v2/generation/1").unwrap();
+    assert_eq!((kind.as_str(), id.as_str()), ("generation", "1"));
+}
+
+#[test]
+fn resource_path_rejects_foreign_or_malformed_urls() {
+    use crate::http::url::resource_path;
+
+    let base = Url::parse("http://localhost:8000/").unwrap();
+    let cases = [
+        "https://pokeapi.co/api/v2/pokemon/25/",
+        "http://localhost:8000/api/v1/pokemon/25/",
+        "http://localhost:8000/api/v2/pokemon/",
+        "http://localhost:8000/api/v2/pokemon/25/encounters",
+        "pokemon/25",
+    ];
+    for url in cases {
+        let err = resource_path(&base, "api/v2", url).unwrap_err();
+        assert!(matches!(err, Error::InvalidArgument { field: "url", .. }), "{url}: {err:?}");
+    }
+}
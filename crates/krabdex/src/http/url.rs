@@ -19,3 +19,51 @@ pub fn join_base(base: &Url, api_prefix: &str, path: &str) -> Result<Url> {
     Ok(url)
 }
 
+
+/// Split a resource URL from the configured instance into `(kind, identifier)`.
+///
+/// The URL must share scheme, host and port with `base` and sit directly
+/// under `api_prefix`, e.g. `https://pokeapi.co/api/v2/pokemon/25/` gives
+/// `("pokemon", "25")`.
+pub(crate) fn resource_path(base: &Url, api_prefix: &str, url: &str) -> Result<(String, String)> {
+    let invalid = |reason: String| Error::InvalidArgument { field: "url", reason };
+
+    let root = join_base(base, api_prefix, "")?;
+    let url = Url::parse(url).map_err(|e| invalid(format!("not a valid URL: {e}")))?;
+    if url.origin() != root.origin() {
+        return Err(invalid(format!("not from the configured instance {}", root.origin().ascii_serialization())));
+    }
+
+    let rest = url
+        .path()
+        .strip_prefix(root.path())
+        .ok_or_else(|| invalid(format!("not under the API prefix {}", root.path())))?;
+    match kind_and_identifier(rest) {
+        Some((kind, identifier)) => Ok((kind.to_string(), identifier.to_string())),
+        None => Err(invalid("expected `<resource>/<id>` after the API prefix".into())),
+    }
+}
+
+/// `(kind, id)` from the last two path segments of a resource link, whatever
+/// the host and API prefix (`.../pokemon/25/` → `("pokemon", 25)`).
+///
+/// Links to sub-resources (`.../pokemon/25/encounters`) and non-numeric ids give `None`.
+pub(crate) fn resource_link(url: &str) -> Option<(&str, u32)> {
+    let url = url.split(['?', '#']).next()?;
+    let path = match url.split_once("://") {
+        Some((_, rest)) => rest.find('/').map_or("", |i| &rest[i..]),
+        None => url,
+    };
+    let path = path.trim_end_matches('/');
+    let start = path.rmatch_indices('/').nth(1).map_or(0, |(i, _)| i + 1);
+    let (kind, id) = kind_and_identifier(&path[start..])?;
+    Some((kind, id.parse().ok()?))
+}
+
+/// Split `<kind>/<identifier>` (surrounding slashes allowed) into its two segments.
+fn kind_and_identifier(path: &str) -> Option<(&str, &str)> {
+    match path.trim_matches('/').split('/').collect::<Vec<_>>()[..] {
+        [kind, identifier] if !kind.is_empty() && !identifier.is_empty() => Some((kind, identifier)),
+        _ => None,
+    }
+}
@@ -0,0 +1,147 @@
+/// All names of one resource kind (e.g. every Pokemon), searchable for
+/// autocomplete and typo recovery.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct NameIndex {
+    names: Vec<String>,
+}
+
+/// How a name matched a search query.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum MatchKind {
+    /// The name is the query.
+    Exact,
+    /// The name starts with the query.
+    Prefix,
+    /// The name contains the query.
+    Substring,
+    /// The name (or its start) is within a small edit distance of the query.
+    Fuzzy,
+}
+
+/// One search result.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NameMatch {
+    pub name: String,
+    pub kind: MatchKind,
+    /// Relevance in `0.0..=1.0`: exact is 1.0, then prefix, substring and
+    /// fuzzy matches in bands, closer matches scoring higher within a band.
+    pub score: f64,
+}
+
+/// Most suggestions attached to a single error.
+const MAX_SUGGESTIONS: usize = 5;
+
+impl NameIndex {
+    pub fn new(mut names: Vec<String>) -> Self {
+        names.sort();
+        names.dedup();
+        Self { names }
+    }
+
+    /// All names, sorted.
+    pub fn names(&self) -> &[String] {
+        &self.names
+    }
+
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    pub fn contains(&self, name: &str) -> bool {
+        self.names.binary_search_by(|n| n.as_str().cmp(name)).is_ok()
+    }
+
+    /// Up to `limit` names matching `query`, best first (ties sorted by name).
+    ///
+    /// Matching is case-insensitive. Fuzzy matches allow one edit per three
+    /// query characters, against the whole name or its first characters, so
+    /// both "pikachuu" and "pikac" with a typo find "pikachu".
+    pub fn search(&self, query: &str, limit: usize) -> Vec<NameMatch> {
+        let query = query.trim().to_ascii_lowercase();
+        if query.is_empty() || limit == 0 {
+            return Vec::new();
+        }
+
+        let mut matches: Vec<NameMatch> = self
+            .names
+            .iter()
+            .filter_map(|name| score(&query, name))
+            .collect();
+        matches.sort_by(|a, b| b.score.total_cmp(&a.score).then_with(|| a.name.cmp(&b.name)));
+        matches.truncate(limit);
+        matches
+    }
+
+    /// Names within a small edit distance of `query`, closest first.
+    pub(crate) fn suggest(&self, query: &str) -> Vec<String> {
+        let query = query.to_ascii_lowercase();
+        let max_distance = max_distance(&query);
+
+        let mut scored: Vec<(usize, &String)> = self
+            .names
+            .iter()
+            .filter(|name| **name != query)
+            .filter_map(|name| {
+                let d = edit_distance(&query, name);
+                (d <= max_distance).then_some((d, name))
+            })
+            .collect();
+        scored.sort();
+        scored
+            .into_iter()
+            .take(MAX_SUGGESTIONS)
+            .map(|(_, name)| name.clone())
+            .collect()
+    }
+}
+
+fn score(query: &str, name: &str) -> Option<NameMatch> {
+    let coverage = query.len() as f64 / name.len().max(1) as f64;
+    let (kind, score) = if name == query {
+        (MatchKind::Exact, 1.0)
+    } else if name.starts_with(query) {
+        (MatchKind::Prefix, 0.75 + 0.2 * coverage)
+    } else if name.contains(query) {
+        (MatchKind::Substring, 0.5 + 0.2 * coverage)
+    } else {
+        let head: String = name.chars().take(query.chars().count()).collect();
+        let distance = edit_distance(query, name).min(edit_distance(query, &head));
+        if distance > max_distance(query) {
+            return None;
+        }
+        let similarity = 1.0 - distance as f64 / query.chars().count().max(1) as f64;
+        (MatchKind::Fuzzy, 0.45 * similarity)
+    };
+
+    Some(NameMatch {
+        name: name.to_string(),
+        kind,
+        score,
+    })
+}
+
+/// Edits tolerated for a query: one per three characters, at least one.
+fn max_distance(query: &str) -> usize {
+    (query.chars().count() / 3).max(1)
+}
+
+/// Levenshtein distance between two strings, counted in chars.
+pub(crate) fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    let mut cur = vec![0; b.len() + 1];
+
+    for (i, ca) in a.chars().enumerate() {
+        cur[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = prev[j] + usize::from(ca != *cb);
+            cur[j + 1] = substitution.min(prev[j + 1] + 1).min(cur[j] + 1);
+        }
+        std::mem::swap(&mut prev, &mut cur);
+    }
+    prev[b.len()]
+}
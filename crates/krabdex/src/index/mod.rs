@@ -0,0 +1,7 @@
+#[allow(clippy::module_inception)]
+pub mod index;
+
+pub use index::{MatchKind, NameIndex, NameMatch};
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,86 @@
+use super::index::{edit_distance, NameIndex};
+
+#[test]
+fn edit_distance_counts_single_edits() {
+    assert_eq!(edit_distance("pikachu", "pikachu"), 0);
+    assert_eq!(edit_distance("pikachuu", "pikachu"), 1);
+    assert_eq!(edit_distance("pikahcu", "pikachu"), 2);
+    assert_eq!(edit_distance("", "abc"), 3);
+    assert_eq!(edit_distance("kitten", "sitting"), 3);
+}
+
+#[test]
+fn suggestions_are_sorted_by_distance_then_name() {
+    let index = NameIndex::new(
+        ["pikachu", "pichu", "raichu", "bulbasaur", "pikachu"]
+            .map(String::from)
+            .to_vec(),
+    );
+
+    assert_eq!(index.suggest("pikachuu"), vec!["pikachu"]);
+    assert_eq!(index.suggest("Pichuu"), vec!["pichu"]);
+    assert_eq!(index.suggest("raichuu"), vec!["raichu"]);
+    assert!(index.suggest("mewtwo").is_empty());
+}
+
+#[test]
+fn suggestions_are_capped() {
+    let index = NameIndex::new((0..20).map(|i| format!("mon-{i}")).collect());
+
+    assert_eq!(index.suggest("mon-x").len(), 5);
+}
+
+fn dex() -> NameIndex {
+    NameIndex::new(
+        ["pikachu", "pikachu-rock-star", "pichu", "raichu", "bulbasaur", "ivysaur", "venusaur", "mr-mime"]
+            .map(String::from)
+            .to_vec(),
+    )
+}
+
+#[test]
+fn search_ranks_exact_prefix_substring_then_fuzzy() {
+    use super::index::MatchKind;
+
+    let results = dex().search("pikachu", 10);
+    let kinds: Vec<(&str, MatchKind)> = results.iter().map(|m| (m.name.as_str(), m.kind)).collect();
+    assert_eq!(
+        kinds,
+        [
+            ("pikachu", MatchKind::Exact),
+            ("pikachu-rock-star", MatchKind::Prefix),
+            ("pichu", MatchKind::Fuzzy),
+        ]
+    );
+    assert!(results.windows(2).all(|w| w[0].score >= w[1].score));
+    assert_eq!(results[0].score, 1.0);
+}
+
+#[test]
+fn search_finds_substrings_and_typos() {
+    use super::index::MatchKind;
+
+    let saurs: Vec<String> = dex().search("saur", 10).into_iter().map(|m| m.name).collect();
+    assert_eq!(saurs, ["ivysaur", "venusaur", "bulbasaur"]);
+
+    let typo = dex().search("Pikahcu", 1);
+    assert_eq!(typo[0].name, "pikachu");
+    assert_eq!(typo[0].kind, MatchKind::Fuzzy);
+
+    // A typo in the first few characters still autocompletes.
+    assert_eq!(dex().search("mr-mim", 1)[0].kind, MatchKind::Prefix);
+    assert_eq!(dex().search("bulbs", 1)[0].name, "bulbasaur");
+}
+
+#[test]
+fn search_handles_empty_queries_and_limits() {
+    assert!(dex().search("  ", 10).is_empty());
+    assert!(dex().search("pi", 0).is_empty());
+    assert_eq!(dex().search("pi", 2).len(), 2);
+    assert!(dex().search("zzzzzz", 10).is_empty());
+
+    let index = dex();
+    assert!(index.contains("mr-mime"));
+    assert!(!index.contains("mr mime"));
+    assert_eq!(index.len(), 8);
+}
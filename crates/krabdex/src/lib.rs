@@ -13,12 +13,20 @@
 //! ```
 
 pub mod error;
-pub(crate) mod http;
+pub mod http;
 pub(crate) mod transport;
 pub mod client;
+pub mod cache;
+pub mod decode;
+pub mod index;
+pub mod metrics;
+pub mod middleware;
 pub mod types;
 pub mod models;
 pub mod api;
+pub mod assets;
+#[cfg(feature = "blocking")]
+pub mod blocking;
 
 pub use client::PokeApiClient;
 pub use error::{Error, Result};
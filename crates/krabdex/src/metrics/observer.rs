@@ -0,0 +1,81 @@
+use std::{fmt, time::Duration};
+
+use crate::{cache::CacheStatus, error::Error};
+
+/// Hook for recording client metrics (request counts, latencies, error classes,
+/// bytes received and cache hit ratio).
+///
+/// Register one with `PokeApiClientBuilder::metrics`. Callbacks run inline on
+/// the request path, so they should be cheap (e.g. bump counters).
+///
+/// ```
+/// use std::sync::atomic::{AtomicU64, Ordering};
+/// use krabdex::metrics::{MetricsObserver, RequestMetrics};
+///
+/// #[derive(Default)]
+/// struct Counter(AtomicU64);
+///
+/// impl MetricsObserver for Counter {
+///     fn on_request(&self, _metrics: &RequestMetrics<'_>) {
+///         self.0.fetch_add(1, Ordering::Relaxed);
+///     }
+/// }
+/// ```
+pub trait MetricsObserver: Send + Sync {
+    /// Called once per API call, after it completes (successfully or not).
+    fn on_request(&self, metrics: &RequestMetrics<'_>) {
+        let _ = metrics;
+    }
+
+    /// Called once per failed API call, after `on_request`.
+    fn on_error(&self, endpoint: &str, error: &Error) {
+        let _ = (endpoint, error);
+    }
+}
+
+impl fmt::Debug for dyn MetricsObserver {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("MetricsObserver")
+    }
+}
+
+/// Measurements for a single API call.
+#[derive(Debug, Clone)]
+pub struct RequestMetrics<'a> {
+    /// Resource collection the call targeted (e.g. `pokemon`, `generation`).
+    pub endpoint: &'a str,
+    /// Final HTTP status (`None` if no response was received).
+    pub status: Option<u16>,
+    /// Wall-clock time of the whole call, including retries and deserialization.
+    pub latency: Duration,
+    /// Number of transport attempts (0 when served from cache).
+    pub attempts: u32,
+    /// Response body bytes received over the network, summed across attempts.
+    pub bytes_received: u64,
+    /// How the response cache was involved (`None` if no response was received).
+    pub cache_status: Option<CacheStatus>,
+}
+
+impl RequestMetrics<'_> {
+    /// Status class label such as `"2xx"` or `"5xx"`.
+    pub fn status_class(&self) -> Option<&'static str> {
+        match self.status? {
+            100..=199 => Some("1xx"),
+            200..=299 => Some("2xx"),
+            300..=399 => Some("3xx"),
+            400..=499 => Some("4xx"),
+            500..=599 => Some("5xx"),
+            _ => None,
+        }
+    }
+
+    /// Whether the response was served from the cache (fresh or revalidated).
+    pub fn is_cache_hit(&self) -> bool {
+        matches!(self.cache_status, Some(CacheStatus::Fresh | CacheStatus::Revalidated))
+    }
+}
+
+/// Endpoint label for a request path: its first segment (`pokemon/25` -> `pokemon`).
+pub(crate) fn endpoint_of(path: &str) -> &str {
+    path.trim_matches('/').split('/').next().unwrap_or_default()
+}
@@ -0,0 +1,79 @@
+use std::{fmt, sync::Arc};
+
+use crate::{
+    error::Result,
+    http::{HttpRequest, HttpResponse},
+    transport::transport::Transport,
+};
+
+/// Hook that can inspect and modify requests before they reach the transport,
+/// and responses after they come back.
+///
+/// Middleware runs once per transport attempt: inside the retry loop and
+/// behind the response cache, so cached responses do not pass through it.
+/// Requests visit middleware in registration order, responses in reverse.
+///
+/// ```
+/// use krabdex::{http::HttpRequest, middleware::Middleware, Result};
+///
+/// struct ApiKey(String);
+///
+/// impl Middleware for ApiKey {
+///     fn on_request(&self, req: &mut HttpRequest) -> Result<Option<krabdex::http::HttpResponse>> {
+///         req.headers.insert("x-api-key", self.0.clone());
+///         Ok(None)
+///     }
+/// }
+/// ```
+pub trait Middleware: Send + Sync {
+    /// Inspect or modify an outgoing request.
+    ///
+    /// Returning `Ok(Some(response))` skips the transport (and later middleware)
+    /// and answers with that response instead, which is handy for stubbing and
+    /// fault injection. Returning an error fails the attempt.
+    fn on_request(&self, req: &mut HttpRequest) -> Result<Option<HttpResponse>> {
+        let _ = req;
+        Ok(None)
+    }
+
+    /// Inspect or modify a response before the client classifies and deserializes it.
+    fn on_response(&self, req: &HttpRequest, resp: &mut HttpResponse) -> Result<()> {
+        let _ = (req, resp);
+        Ok(())
+    }
+}
+
+impl fmt::Debug for dyn Middleware {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Middleware")
+    }
+}
+
+/// Ordered middleware wrapped around a transport.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct MiddlewareStack(Vec<Arc<dyn Middleware>>);
+
+impl MiddlewareStack {
+    pub fn push(&mut self, middleware: Arc<dyn Middleware>) {
+        self.0.push(middleware);
+    }
+
+    /// Run the request through every layer, send it, and run the response back out.
+    pub async fn send<T: Transport>(&self, transport: &T, mut req: HttpRequest) -> Result<HttpResponse> {
+        for (i, layer) in self.0.iter().enumerate() {
+            if let Some(mut resp) = layer.on_request(&mut req)? {
+                // Only the layers that saw the request see the response.
+                for outer in self.0[..i].iter().rev() {
+                    outer.on_response(&req, &mut resp)?;
+                }
+                return Ok(resp);
+            }
+        }
+
+        let mut resp = transport.send(req.clone()).await?;
+        for layer in self.0.iter().rev() {
+            layer.on_response(&req, &mut resp)?;
+        }
+        Ok(resp)
+    }
+}
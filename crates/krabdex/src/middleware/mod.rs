@@ -0,0 +1,7 @@
+#[allow(clippy::module_inception)]
+pub mod middleware;
+
+pub use middleware::Middleware;
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,102 @@
+use std::sync::{Arc, Mutex};
+
+use url::Url;
+
+use crate::{
+    error::{Error, Result},
+    http::{Headers, HttpRequest, HttpResponse, Method},
+    middleware::middleware::{Middleware, MiddlewareStack},
+    transport::transport::Transport,
+};
+
+/// Transport that echoes request headers back as response headers.
+struct EchoTransport;
+
+impl Transport for EchoTransport {
+    async fn send(&self, req: HttpRequest) -> Result<HttpResponse> {
+        Ok(HttpResponse {
+            status: 200,
+            headers: req.headers,
+            body: b"ok".to_vec(),
+        })
+    }
+}
+
+/// Records the order it is called in under `name`.
+struct Tracer {
+    name: &'static str,
+    log: Arc<Mutex<Vec<String>>>,
+    short_circuit: bool,
+}
+
+impl Middleware for Tracer {
+    fn on_request(&self, req: &mut HttpRequest) -> Result<Option<HttpResponse>> {
+        self.log.lock().unwrap().push(format!("req:{}", self.name));
+        req.headers.insert(format!("x-{}", self.name), "1");
+        if self.short_circuit {
+            return Ok(Some(HttpResponse {
+                status: 503,
+                headers: Headers::new(),
+                body: Vec::new(),
+            }));
+        }
+        Ok(None)
+    }
+
+    fn on_response(&self, _req: &HttpRequest, _resp: &mut HttpResponse) -> Result<()> {
+        self.log.lock().unwrap().push(format!("resp:{}", self.name));
+        Ok(())
+    }
+}
+
+fn request() -> HttpRequest {
+    HttpRequest::new(Method::Get, Url::parse("https://pokeapi.co/api/v2/pokemon").unwrap())
+}
+
+fn tracer(name: &'static str, log: &Arc<Mutex<Vec<String>>>, short_circuit: bool) -> Arc<dyn Middleware> {
+    Arc::new(Tracer { name, log: log.clone(), short_circuit })
+}
+
+#[tokio::test]
+async fn requests_run_in_order_and_responses_in_reverse() {
+    let log = Arc::new(Mutex::new(Vec::new()));
+    let mut stack = MiddlewareStack::default();
+    stack.push(tracer("a", &log, false));
+    stack.push(tracer("b", &log, false));
+
+    let resp = stack.send(&EchoTransport, request()).await.unwrap();
+
+    assert_eq!(resp.headers.get("x-a"), Some("1"));
+    assert_eq!(resp.headers.get("x-b"), Some("1"));
+    assert_eq!(*log.lock().unwrap(), vec!["req:a", "req:b", "resp:b", "resp:a"]);
+}
+
+#[tokio::test]
+async fn short_circuit_skips_transport_and_inner_layers() {
+    let log = Arc::new(Mutex::new(Vec::new()));
+    let mut stack = MiddlewareStack::default();
+    stack.push(tracer("outer", &log, false));
+    stack.push(tracer("fault", &log, true));
+    stack.push(tracer("inner", &log, false));
+
+    let resp = stack.send(&EchoTransport, request()).await.unwrap();
+
+    assert_eq!(resp.status, 503);
+    assert_eq!(*log.lock().unwrap(), vec!["req:outer", "req:fault", "resp:outer"]);
+}
+
+#[tokio::test]
+async fn errors_abort_the_attempt() {
+    struct Fail;
+    impl Middleware for Fail {
+        fn on_request(&self, _req: &mut HttpRequest) -> Result<Option<HttpResponse>> {
+            Err(Error::Internal("injected"))
+        }
+    }
+
+    let mut stack = MiddlewareStack::default();
+    stack.push(Arc::new(Fail));
+
+    let err = stack.send(&EchoTransport, request()).await.unwrap_err();
+    assert!(matches!(err, Error::Internal("injected")));
+}
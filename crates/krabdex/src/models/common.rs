@@ -1,5 +1,7 @@
 use serde::{Deserialize, Serialize};
 
+use crate::http::url::resource_link;
+
 /// Named PokeAPI resource (name + URL).
 #[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
 pub struct NamedApiResource {
@@ -7,12 +9,39 @@ pub struct NamedApiResource {
     pub url: String,
 }
 
+impl NamedApiResource {
+    /// Numeric id in `url` (`.../pokemon/25/` → `25`).
+    ///
+    /// Does not check which instance the URL points at; use
+    /// `PokeApiClient::resource_id` for that.
+    pub fn id(&self) -> Option<u32> {
+        resource_link(&self.url).map(|(_, id)| id)
+    }
+
+    /// Resource kind in `url` (`.../pokemon/25/` → `"pokemon"`).
+    pub fn resource_kind(&self) -> Option<&str> {
+        resource_link(&self.url).map(|(kind, _)| kind)
+    }
+}
+
 /// Unnamed PokeAPI resource (URL only).
 #[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
 pub struct ApiResource {
     pub url: String,
 }
 
+impl ApiResource {
+    /// Numeric id in `url` (`.../evolution-chain/10/` → `10`).
+    pub fn id(&self) -> Option<u32> {
+        resource_link(&self.url).map(|(_, id)| id)
+    }
+
+    /// Resource kind in `url` (`.../evolution-chain/10/` → `"evolution-chain"`).
+    pub fn resource_kind(&self) -> Option<&str> {
+        resource_link(&self.url).map(|(kind, _)| kind)
+    }
+}
+
 /// Localized name in a specific language.
 #[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 pub struct Name {
@@ -0,0 +1,50 @@
+use serde::{Deserialize, Serialize};
+
+use crate::models::{
+    common::{Name, NamedApiResource},
+    names::TypeName,
+    pokemon::PokemonTypeSlot,
+    sprites::PokemonSprites,
+};
+
+/// PokeAPI Pokémon form resource (`/pokemon-form/{id or name}`).
+///
+/// Forms are cosmetic or battle-only variations of a `Pokemon` (Unown letters,
+/// mega evolutions, ...); each belongs to exactly one Pokémon.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct PokemonForm {
+    pub id: u32,
+    pub name: String,
+    pub order: u32,
+    pub form_order: u32,
+    pub is_default: bool,
+    pub is_battle_only: bool,
+    pub is_mega: bool,
+    /// Form-specific part of the name (`"mega-x"`, `"alola"`; empty for the base form).
+    pub form_name: String,
+
+    /// The Pokémon this form belongs to.
+    pub pokemon: NamedApiResource,
+    /// Types of this form, which may differ from the Pokémon's.
+    pub types: Vec<PokemonTypeSlot>,
+    pub sprites: PokemonSprites,
+    /// Version group the form was introduced in.
+    pub version_group: NamedApiResource,
+
+    #[serde(default)]
+    pub names: Vec<Name>,
+    #[serde(default)]
+    pub form_names: Vec<Name>,
+}
+
+impl PokemonForm {
+    /// Type in slot 1.
+    pub fn primary_type(&self) -> Option<TypeName> {
+        self.types.iter().find(|t| t.slot == 1).map(PokemonTypeSlot::type_name)
+    }
+
+    /// Type in slot 2, if the form is dual-typed.
+    pub fn secondary_type(&self) -> Option<TypeName> {
+        self.types.iter().find(|t| t.slot == 2).map(PokemonTypeSlot::type_name)
+    }
+}
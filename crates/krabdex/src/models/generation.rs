@@ -1,6 +1,9 @@
 use serde::{Deserialize, Serialize};
 
-use crate::models::common::{NamedApiResource, Name};
+use crate::models::{
+    common::{NamedApiResource, Name},
+    names::{TypeName, VersionGroupName},
+};
 
 /// PokeAPI Generation resource.
 #[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
@@ -17,3 +20,67 @@ pub struct Generation {
     pub main_region: NamedApiResource,
     pub names: Vec<Name>,
 }
+
+/// Position of a generation in release order (`generation-i` is 1).
+///
+/// Used to compare generations, e.g. in `Pokemon::types_in`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct GenerationNumber(pub u32);
+
+impl GenerationNumber {
+    /// Number of a generation link, from its URL id or else its roman-numeral name.
+    pub fn of(resource: &NamedApiResource) -> Option<Self> {
+        resource
+            .id()
+            .or_else(|| roman(resource.name.strip_prefix("generation-")?))
+            .map(Self)
+    }
+}
+
+impl From<u32> for GenerationNumber {
+    fn from(n: u32) -> Self {
+        Self(n)
+    }
+}
+
+impl From<&Generation> for GenerationNumber {
+    fn from(generation: &Generation) -> Self {
+        generation.number()
+    }
+}
+
+/// Value of a lowercase roman numeral (`"vi"` → 6).
+fn roman(s: &str) -> Option<u32> {
+    let digit = |c| match c {
+        'i' => Some(1),
+        'v' => Some(5),
+        'x' => Some(10),
+        _ => None,
+    };
+    let digits = s.chars().map(digit).collect::<Option<Vec<u32>>>()?;
+    let total = digits.iter().enumerate().fold(0i64, |acc, (i, &d)| {
+        if digits.get(i + 1).is_some_and(|&next| next > d) {
+            acc - i64::from(d)
+        } else {
+            acc + i64::from(d)
+        }
+    });
+    u32::try_from(total).ok().filter(|&n| n > 0)
+}
+
+impl Generation {
+    /// Release order of this generation (its id).
+    pub fn number(&self) -> GenerationNumber {
+        GenerationNumber(self.id)
+    }
+
+    /// Types introduced in this generation.
+    pub fn type_names(&self) -> Vec<TypeName> {
+        self.types.iter().map(TypeName::from).collect()
+    }
+
+    /// Version groups belonging to this generation.
+    pub fn version_group_names(&self) -> Vec<VersionGroupName> {
+        self.version_groups.iter().map(VersionGroupName::from).collect()
+    }
+}
@@ -3,4 +3,9 @@
 
 pub mod common;
 pub mod pokemon;
-pub mod generation;
\ No newline at end of file
+pub mod generation;
+pub mod form;
+pub mod species;
+pub mod sprites;
+pub mod names;
+pub mod units;
\ No newline at end of file
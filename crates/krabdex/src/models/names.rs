@@ -0,0 +1,203 @@
+//! Typed names for well-known PokeAPI resources.
+//!
+//! Each enum parses from the API's slug and falls back to `Unknown(slug)` for
+//! names added upstream after this crate was released, so parsing never fails.
+
+use std::{convert::Infallible, fmt, str::FromStr};
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::models::common::NamedApiResource;
+
+macro_rules! known_names {
+    (
+        $(#[$meta:meta])*
+        pub enum $name:ident {
+            $($variant:ident => $slug:literal,)+
+        }
+    ) => {
+        $(#[$meta])*
+        ///
+        /// Equality and hashing go by the slug, so `Unknown("fire")` equals `Fire`.
+        #[derive(Debug, Clone)]
+        pub enum $name {
+            $($variant,)+
+            /// A name this version of krabdex does not know yet.
+            Unknown(String),
+        }
+
+        impl PartialEq for $name {
+            fn eq(&self, other: &Self) -> bool {
+                self.as_str() == other.as_str()
+            }
+        }
+
+        impl Eq for $name {}
+
+        impl std::hash::Hash for $name {
+            fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+                self.as_str().hash(state);
+            }
+        }
+
+        impl $name {
+            /// Every known variant, in PokeAPI order.
+            pub const ALL: &'static [$name] = &[$($name::$variant,)+];
+
+            /// The PokeAPI slug (e.g. `"special-attack"`).
+            pub fn as_str(&self) -> &str {
+                match self {
+                    $($name::$variant => $slug,)+
+                    $name::Unknown(s) => s,
+                }
+            }
+
+            pub fn is_unknown(&self) -> bool {
+                matches!(self, $name::Unknown(_))
+            }
+        }
+
+        impl From<&str> for $name {
+            fn from(s: &str) -> Self {
+                match s {
+                    $($slug => $name::$variant,)+
+                    other => $name::Unknown(other.to_string()),
+                }
+            }
+        }
+
+        impl From<&NamedApiResource> for $name {
+            fn from(resource: &NamedApiResource) -> Self {
+                Self::from(resource.name.as_str())
+            }
+        }
+
+        impl FromStr for $name {
+            type Err = Infallible;
+
+            fn from_str(s: &str) -> Result<Self, Infallible> {
+                Ok(Self::from(s))
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+
+        impl Serialize for $name {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_str(self.as_str())
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $name {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let s = String::deserialize(deserializer)?;
+                Ok(Self::from(s.as_str()))
+            }
+        }
+    };
+}
+
+known_names! {
+    /// Elemental type (`fire`, `water`, ...).
+    ///
+    /// PokeAPI's own `unknown` type parses as `Unknown("unknown")`.
+    pub enum TypeName {
+        Normal => "normal",
+        Fighting => "fighting",
+        Flying => "flying",
+        Poison => "poison",
+        Ground => "ground",
+        Rock => "rock",
+        Bug => "bug",
+        Ghost => "ghost",
+        Steel => "steel",
+        Fire => "fire",
+        Water => "water",
+        Grass => "grass",
+        Electric => "electric",
+        Psychic => "psychic",
+        Ice => "ice",
+        Dragon => "dragon",
+        Dark => "dark",
+        Fairy => "fairy",
+        Stellar => "stellar",
+        Shadow => "shadow",
+    }
+}
+
+known_names! {
+    /// Battle stat (`hp`, `special-attack`, ...).
+    pub enum StatName {
+        Hp => "hp",
+        Attack => "attack",
+        Defense => "defense",
+        SpecialAttack => "special-attack",
+        SpecialDefense => "special-defense",
+        Speed => "speed",
+        Accuracy => "accuracy",
+        Evasion => "evasion",
+    }
+}
+
+known_names! {
+    /// Whether a move deals physical, special or no damage.
+    pub enum MoveDamageClass {
+        Status => "status",
+        Physical => "physical",
+        Special => "special",
+    }
+}
+
+known_names! {
+    /// How a Pokémon learns a move.
+    pub enum MoveLearnMethod {
+        LevelUp => "level-up",
+        Egg => "egg",
+        Tutor => "tutor",
+        Machine => "machine",
+        StadiumSurfingPikachu => "stadium-surfing-pikachu",
+        LightBallEgg => "light-ball-egg",
+        ColosseumPurification => "colosseum-purification",
+        XdShadow => "xd-shadow",
+        XdPurification => "xd-purification",
+        FormChange => "form-change",
+        ZygardeCube => "zygarde-cube",
+    }
+}
+
+known_names! {
+    /// Group of game versions sharing data (`red-blue`, `scarlet-violet`, ...).
+    pub enum VersionGroupName {
+        RedBlue => "red-blue",
+        Yellow => "yellow",
+        GoldSilver => "gold-silver",
+        Crystal => "crystal",
+        RubySapphire => "ruby-sapphire",
+        Emerald => "emerald",
+        FireredLeafgreen => "firered-leafgreen",
+        Colosseum => "colosseum",
+        Xd => "xd",
+        DiamondPearl => "diamond-pearl",
+        Platinum => "platinum",
+        HeartgoldSoulsilver => "heartgold-soulsilver",
+        BlackWhite => "black-white",
+        Black2White2 => "black-2-white-2",
+        XY => "x-y",
+        OmegaRubyAlphaSapphire => "omega-ruby-alpha-sapphire",
+        SunMoon => "sun-moon",
+        UltraSunUltraMoon => "ultra-sun-ultra-moon",
+        LetsGoPikachuLetsGoEevee => "lets-go-pikachu-lets-go-eevee",
+        SwordShield => "sword-shield",
+        TheIsleOfArmor => "the-isle-of-armor",
+        TheCrownTundra => "the-crown-tundra",
+        BrilliantDiamondAndShiningPearl => "brilliant-diamond-and-shining-pearl",
+        LegendsArceus => "legends-arceus",
+        ScarletViolet => "scarlet-violet",
+        TheTealMask => "the-teal-mask",
+        TheIndigoDisk => "the-indigo-disk",
+    }
+}
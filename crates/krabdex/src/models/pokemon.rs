@@ -1,7 +1,12 @@
 use serde::{Deserialize, Serialize};
-use serde_json::Value;
 
-use crate::models::common::{NamedApiResource, VersionGameIndex};
+use crate::models::{
+    common::{NamedApiResource, VersionGameIndex},
+    generation::GenerationNumber,
+    names::{MoveLearnMethod, StatName, TypeName, VersionGroupName},
+    sprites::PokemonSprites,
+    units::{Height, Weight},
+};
 
 /// PokeAPI Pokémon resource.
 #[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
@@ -30,14 +35,103 @@ pub struct Pokemon {
     #[serde(default)]
     pub past_abilities: Vec<PastAbility>,
 
-    /// Explicitly acknowledged but not modeled.
-    /// This preserves the full payload without schema explosion.
-    pub sprites: Value,
+    pub sprites: PokemonSprites,
 
     #[serde(default)]
     pub cries: Option<PokemonCries>,
 }
 
+impl Pokemon {
+    /// Sum of all base stats.
+    pub fn base_stat_total(&self) -> u32 {
+        self.stats.iter().map(|s| s.base_stat).sum()
+    }
+
+    /// Base value of one stat.
+    pub fn stat(&self, name: StatName) -> Option<u32> {
+        self.stats
+            .iter()
+            .find(|s| s.stat_name() == name)
+            .map(|s| s.base_stat)
+    }
+
+    /// Type in slot 1.
+    pub fn primary_type(&self) -> Option<TypeName> {
+        self.type_in_slot(1)
+    }
+
+    /// Type in slot 2, if the Pokémon is dual-typed.
+    pub fn secondary_type(&self) -> Option<TypeName> {
+        self.type_in_slot(2)
+    }
+
+    fn type_in_slot(&self, slot: u8) -> Option<TypeName> {
+        self.types
+            .iter()
+            .find(|t| t.slot == slot)
+            .map(PokemonTypeSlot::type_name)
+    }
+
+    /// The hidden ability, if it has one.
+    pub fn hidden_ability(&self) -> Option<&NamedApiResource> {
+        self.abilities
+            .iter()
+            .find(|a| a.is_hidden)
+            .and_then(|a| a.ability.as_ref())
+    }
+
+    /// Types as they were in `generation` (e.g. Clefairy is Normal before generation VI).
+    ///
+    /// Each `past_types` entry holds the typing used up to and including its
+    /// generation, so the earliest entry not before `generation` wins.
+    pub fn types_in(&self, generation: impl Into<GenerationNumber>) -> &[PokemonTypeSlot] {
+        let generation = generation.into();
+        self.past_types
+            .iter()
+            .filter_map(|past| Some((GenerationNumber::of(&past.generation)?, past)))
+            .filter(|(until, _)| *until >= generation)
+            .min_by_key(|(until, _)| *until)
+            .map_or(&self.types[..], |(_, past)| &past.types[..])
+    }
+
+    /// Abilities as they were in `generation`, sorted by slot.
+    ///
+    /// `past_abilities` entries only list the slots that changed; a slot with
+    /// no ability (e.g. hidden abilities before generation V) is left out.
+    pub fn abilities_in(&self, generation: impl Into<GenerationNumber>) -> Vec<PokemonAbility> {
+        let generation = generation.into();
+        let mut past: Vec<(GenerationNumber, &PastAbility)> = self
+            .past_abilities
+            .iter()
+            .filter_map(|past| Some((GenerationNumber::of(&past.generation)?, past)))
+            .filter(|(until, _)| *until >= generation)
+            .collect();
+        // Apply later entries first so the one closest to `generation` wins.
+        past.sort_by_key(|(until, _)| std::cmp::Reverse(*until));
+
+        let mut abilities = self.abilities.clone();
+        for (_, entry) in past {
+            for changed in &entry.abilities {
+                abilities.retain(|a| a.slot != changed.slot);
+                abilities.push(changed.clone());
+            }
+        }
+        abilities.retain(|a| a.ability.is_some());
+        abilities.sort_by_key(|a| a.slot);
+        abilities
+    }
+
+    /// Height with units (the raw `height` field is in decimetres).
+    pub fn height_units(&self) -> Height {
+        Height::from_decimetres(self.height)
+    }
+
+    /// Weight with units (the raw `weight` field is in hectograms).
+    pub fn weight_units(&self) -> Weight {
+        Weight::from_hectograms(self.weight)
+    }
+}
+
 /* ---------- Abilities ---------- */
 
 /// Ability info attached to a Pokémon.
@@ -82,6 +176,16 @@ pub struct MoveVersionGroupDetail {
     pub version_group: NamedApiResource,
 }
 
+impl MoveVersionGroupDetail {
+    pub fn learn_method(&self) -> MoveLearnMethod {
+        MoveLearnMethod::from(&self.move_learn_method)
+    }
+
+    pub fn version_group_name(&self) -> VersionGroupName {
+        VersionGroupName::from(&self.version_group)
+    }
+}
+
 /* ---------- Stats ---------- */
 
 /// Stat entry for a Pokémon.
@@ -92,6 +196,12 @@ pub struct PokemonStat {
     pub stat: NamedApiResource,
 }
 
+impl PokemonStat {
+    pub fn stat_name(&self) -> StatName {
+        StatName::from(&self.stat)
+    }
+}
+
 /* ---------- Types ---------- */
 
 /// Type slot info (includes slot number).
@@ -103,6 +213,12 @@ pub struct PokemonTypeSlot {
     pub ty: NamedApiResource,
 }
 
+impl PokemonTypeSlot {
+    pub fn type_name(&self) -> TypeName {
+        TypeName::from(&self.ty)
+    }
+}
+
 /* ---------- Past Types ---------- */
 
 /// Historical type assignments for a Pokémon.
@@ -0,0 +1,98 @@
+use serde::{Deserialize, Serialize};
+
+use crate::models::common::{ApiResource, Name, NamedApiResource};
+
+/// PokeAPI Pokémon species resource (`/pokemon-species/{id or name}`).
+///
+/// A species groups its varieties: the default Pokémon plus regional forms,
+/// megas, Gigantamax and other variants that have their own stats.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct PokemonSpecies {
+    pub id: u32,
+    pub name: String,
+    pub order: u32,
+    /// Chance of being female in eighths, or `-1` for genderless species.
+    pub gender_rate: i8,
+    pub capture_rate: u32,
+    pub base_happiness: Option<u32>,
+    pub is_baby: bool,
+    pub is_legendary: bool,
+    pub is_mythical: bool,
+    pub hatch_counter: Option<u32>,
+    pub has_gender_differences: bool,
+    pub forms_switchable: bool,
+
+    pub growth_rate: NamedApiResource,
+    pub pokedex_numbers: Vec<PokedexNumber>,
+    pub egg_groups: Vec<NamedApiResource>,
+    pub color: NamedApiResource,
+    pub shape: Option<NamedApiResource>,
+    pub evolves_from_species: Option<NamedApiResource>,
+    pub evolution_chain: Option<ApiResource>,
+    pub habitat: Option<NamedApiResource>,
+    pub generation: NamedApiResource,
+
+    pub names: Vec<Name>,
+    #[serde(default)]
+    pub pal_park_encounters: Vec<PalParkEncounter>,
+    #[serde(default)]
+    pub flavor_text_entries: Vec<FlavorText>,
+    #[serde(default)]
+    pub form_descriptions: Vec<Description>,
+    #[serde(default)]
+    pub genera: Vec<Genus>,
+
+    pub varieties: Vec<PokemonSpeciesVariety>,
+}
+
+impl PokemonSpecies {
+    /// The variety marked as default (e.g. `pikachu` for the Pikachu species).
+    pub fn default_variety(&self) -> Option<&NamedApiResource> {
+        self.varieties.iter().find(|v| v.is_default).map(|v| &v.pokemon)
+    }
+}
+
+/// One Pokémon belonging to a species.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct PokemonSpeciesVariety {
+    pub is_default: bool,
+    pub pokemon: NamedApiResource,
+}
+
+/// Species entry number in a regional or national Pokédex.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct PokedexNumber {
+    pub entry_number: u32,
+    pub pokedex: NamedApiResource,
+}
+
+/// Pal Park encounter details.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct PalParkEncounter {
+    pub base_score: u32,
+    pub rate: u32,
+    pub area: NamedApiResource,
+}
+
+/// Pokédex flavor text for one game version.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct FlavorText {
+    pub flavor_text: String,
+    pub language: NamedApiResource,
+    #[serde(default)]
+    pub version: Option<NamedApiResource>,
+}
+
+/// Localized description.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct Description {
+    pub description: String,
+    pub language: NamedApiResource,
+}
+
+/// Localized genus ("Mouse Pokémon").
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct Genus {
+    pub genus: String,
+    pub language: NamedApiResource,
+}
@@ -0,0 +1,166 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+/// Sprite URLs for a Pokémon.
+///
+/// Every image is optional: PokeAPI returns `null` for variants that do not
+/// exist (e.g. female sprites of genderless species).
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct PokemonSprites {
+    #[serde(default)]
+    pub front_default: Option<String>,
+    #[serde(default)]
+    pub front_female: Option<String>,
+    #[serde(default)]
+    pub front_shiny: Option<String>,
+    #[serde(default)]
+    pub front_shiny_female: Option<String>,
+    #[serde(default)]
+    pub back_default: Option<String>,
+    #[serde(default)]
+    pub back_female: Option<String>,
+    #[serde(default)]
+    pub back_shiny: Option<String>,
+    #[serde(default)]
+    pub back_shiny_female: Option<String>,
+
+    /// Artwork from other sources (official artwork, HOME, ...).
+    #[serde(default)]
+    pub other: OtherSprites,
+
+    /// Game sprites keyed by generation (`generation-i`) and then version group (`red-blue`).
+    #[serde(default)]
+    pub versions: BTreeMap<String, BTreeMap<String, SpriteSet>>,
+}
+
+/// Artwork sets under `sprites.other`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct OtherSprites {
+    #[serde(default)]
+    pub dream_world: SpriteSet,
+    #[serde(default)]
+    pub home: SpriteSet,
+    #[serde(default, rename = "official-artwork")]
+    pub official_artwork: SpriteSet,
+    #[serde(default)]
+    pub showdown: SpriteSet,
+}
+
+/// One set of sprite images; sources only fill in the variants they provide.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct SpriteSet {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub front_default: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub front_female: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub front_shiny: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub front_shiny_female: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub back_default: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub back_female: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub back_shiny: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub back_shiny_female: Option<String>,
+
+    /// Greyscale and transparent variants (generations I and II only).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub front_gray: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub back_gray: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub front_transparent: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub back_transparent: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub front_shiny_transparent: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub back_shiny_transparent: Option<String>,
+
+    /// Animated variants (Black/White only).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub animated: Option<Box<SpriteSet>>,
+}
+
+/// Which style of image `PokemonSprites::best` should prefer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SpriteKind {
+    /// Default front-facing game sprite.
+    Front,
+    /// Back-facing game sprite.
+    Back,
+    /// High resolution official artwork.
+    OfficialArtwork,
+    /// Pokémon HOME render.
+    Home,
+    /// Dream World vector artwork.
+    DreamWorld,
+    /// Animated Showdown sprite.
+    Showdown,
+}
+
+impl SpriteKind {
+    /// Fallback order when the preferred kind has no image.
+    const FALLBACK: [SpriteKind; 6] = [
+        SpriteKind::OfficialArtwork,
+        SpriteKind::Home,
+        SpriteKind::Front,
+        SpriteKind::DreamWorld,
+        SpriteKind::Showdown,
+        SpriteKind::Back,
+    ];
+}
+
+impl SpriteSet {
+    /// Front image, shiny or not.
+    pub fn front(&self, shiny: bool) -> Option<&str> {
+        if shiny { self.front_shiny.as_deref() } else { self.front_default.as_deref() }
+    }
+
+    /// Back image, shiny or not.
+    pub fn back(&self, shiny: bool) -> Option<&str> {
+        if shiny { self.back_shiny.as_deref() } else { self.back_default.as_deref() }
+    }
+}
+
+impl PokemonSprites {
+    /// Best available image URL, starting from `kind`.
+    ///
+    /// Shininess is honoured before kind: a shiny request tries every kind's
+    /// shiny image (starting with `kind`, then official artwork, HOME, front,
+    /// dream world, showdown, back) before settling for a non-shiny image.
+    pub fn best(&self, kind: SpriteKind, shiny: bool) -> Option<&str> {
+        let order = || {
+            std::iter::once(kind).chain(SpriteKind::FALLBACK.into_iter().filter(move |k| *k != kind))
+        };
+        let shiny_first = if shiny { &[true, false][..] } else { &[false][..] };
+
+        shiny_first
+            .iter()
+            .flat_map(|&shiny| order().map(move |k| (k, shiny)))
+            .find_map(|(k, shiny)| self.get(k, shiny))
+    }
+
+    /// Image of exactly `kind`, without fallback.
+    pub fn get(&self, kind: SpriteKind, shiny: bool) -> Option<&str> {
+        match kind {
+            SpriteKind::Front if shiny => self.front_shiny.as_deref(),
+            SpriteKind::Front => self.front_default.as_deref(),
+            SpriteKind::Back if shiny => self.back_shiny.as_deref(),
+            SpriteKind::Back => self.back_default.as_deref(),
+            SpriteKind::OfficialArtwork => self.other.official_artwork.front(shiny),
+            SpriteKind::Home => self.other.home.front(shiny),
+            SpriteKind::DreamWorld => self.other.dream_world.front(shiny),
+            SpriteKind::Showdown => self.other.showdown.front(shiny),
+        }
+    }
+
+    /// Game sprites for a version group, e.g. `version("generation-i", "red-blue")`.
+    pub fn version(&self, generation: &str, version_group: &str) -> Option<&SpriteSet> {
+        self.versions.get(generation)?.get(version_group)
+    }
+}
@@ -0,0 +1,57 @@
+use std::fmt;
+
+/// A height as reported by PokeAPI, in decimetres.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Height(u32);
+
+impl Height {
+    pub fn from_decimetres(dm: u32) -> Self {
+        Self(dm)
+    }
+
+    pub fn decimetres(self) -> u32 {
+        self.0
+    }
+
+    pub fn centimetres(self) -> u32 {
+        self.0 * 10
+    }
+
+    pub fn metres(self) -> f64 {
+        f64::from(self.0) / 10.0
+    }
+}
+
+impl fmt::Display for Height {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:.1} m", self.metres())
+    }
+}
+
+/// A weight as reported by PokeAPI, in hectograms.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Weight(u32);
+
+impl Weight {
+    pub fn from_hectograms(hg: u32) -> Self {
+        Self(hg)
+    }
+
+    pub fn hectograms(self) -> u32 {
+        self.0
+    }
+
+    pub fn grams(self) -> u32 {
+        self.0 * 100
+    }
+
+    pub fn kilograms(self) -> f64 {
+        f64::from(self.0) / 10.0
+    }
+}
+
+impl fmt::Display for Weight {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:.1} kg", self.kilograms())
+    }
+}
@@ -1,5 +1,7 @@
+use std::{error::Error as StdError, io};
+
 use crate::{
-    error::{Error, Result},
+    error::{Error, Result, TransportErrorKind},
     http::{Headers, HttpRequest, HttpResponse, Method},
     transport::transport::Transport,
 };
@@ -18,7 +20,7 @@ impl Transport for ReqwestTransport {
 
     // Map our HTTP types to Reqwest (Hyper)
     async fn send(&self, request: HttpRequest) -> Result<HttpResponse> {
-        let HttpRequest { method, url, headers, query } = request;
+        let HttpRequest { method, url, headers, query, timeout } = request;
 
         let mut request_builder = match method {
             Method::Get => self.client.get(url),
@@ -35,11 +37,15 @@ impl Transport for ReqwestTransport {
             request_builder = request_builder.header(k, v);
         }
 
+        if let Some(t) = timeout {
+            request_builder = request_builder.timeout(t);
+        }
+
         // Send
         let resp = request_builder
             .send()
             .await
-            .map_err(|e| Error::Transport { source: Box::new(e) })?;
+            .map_err(transport_error)?;
 
         let status = resp.status().as_u16();
 
@@ -55,9 +61,42 @@ impl Transport for ReqwestTransport {
         let body = resp
             .bytes()
             .await
-            .map_err(|e| Error::Transport { source: Box::new(e) })?
+            .map_err(transport_error)?
             .to_vec();
 
         Ok(HttpResponse { status, headers, body })
     }
 }
+
+/// Classify a reqwest failure into a `TransportErrorKind`.
+///
+/// TLS failures surface as connect errors in reqwest, so the source chain is
+/// inspected for a `rustls::Error` before falling back to `Connect`.
+pub(crate) fn transport_error(e: reqwest::Error) -> Error {
+    let kind = if e.is_timeout() {
+        TransportErrorKind::Timeout
+    } else if is_tls(&e) {
+        TransportErrorKind::Tls
+    } else if e.is_connect() {
+        TransportErrorKind::Connect
+    } else {
+        TransportErrorKind::Other
+    };
+    Error::transport(kind, e)
+}
+
+fn is_tls(e: &reqwest::Error) -> bool {
+    let mut next = e.source();
+    while let Some(err) = next {
+        if err.is::<rustls::Error>() {
+            return true;
+        }
+        // `io::Error::source` skips the error it wraps (often another
+        // `io::Error` around the rustls one), so unwrap it explicitly.
+        next = match err.downcast_ref::<io::Error>().and_then(io::Error::get_ref) {
+            Some(inner) => Some(inner as &(dyn StdError + 'static)),
+            None => err.source(),
+        };
+    }
+    false
+}
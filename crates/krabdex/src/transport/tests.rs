@@ -82,7 +82,9 @@ async fn reqwest_transport_maps_network_errors() {
     let err = transport.send(req).await.unwrap_err();
 
     match err {
-        crate::error::Error::Transport { .. } => {}
+        crate::error::Error::Transport { kind, .. } => {
+            assert_eq!(kind, crate::error::TransportErrorKind::Connect);
+        }
         other => panic!("expected Transport error, got {other:?}"),
     }
 }
@@ -1,7 +1,11 @@
+use std::{borrow::Borrow, fmt, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+
 use crate::error::{Error, Result};
 
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
 /// A validated Pokemon name identifier used for PokeAPI path and query parameters.
 ///
 /// This wrapper ensures the name matches the PokeAPI's expected format
@@ -31,7 +35,7 @@ pub enum PokemonRef<'a> {
 ///
 /// PokeAPI generation names are lowercase ASCII strings
 /// like: "generation-i", "generation-ii", etc.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct GenerationName<'a>(&'a str);
 impl<'a> GenerationName<'a> {
     pub fn new(name: &'a str) -> Result<Self> {
@@ -51,6 +55,134 @@ pub enum GenerationRef<'a> {
     Name(GenerationName<'a>),
 }
 
+/// Kind and numeric id of a resource URL (`.../pokemon/25/`).
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ResourceId {
+    pub kind: String,
+    pub id: u32,
+}
+
+/// Owned `PokemonName`, for storing in structs, sending across tasks or
+/// deserializing from config. Validated on construction and deserialization.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct PokemonNameBuf(String);
+
+/// Owned `GenerationName`; see `PokemonNameBuf`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct GenerationNameBuf(String);
+
+macro_rules! owned_name {
+    ($owned:ident, $borrowed:ident, $reference:ident, $field:literal) => {
+        impl $owned {
+            pub fn new(name: impl Into<String>) -> Result<Self> {
+                let name = name.into();
+                validate_pokeapi_name(&name, $field)?;
+                Ok(Self(name))
+            }
+
+            pub fn as_str(&self) -> &str {
+                &self.0
+            }
+
+            /// Borrow as the name type accepted by the client methods.
+            pub fn as_name(&self) -> $borrowed<'_> {
+                $borrowed(&self.0)
+            }
+
+            pub fn into_string(self) -> String {
+                self.0
+            }
+        }
+
+        impl<'a> $borrowed<'a> {
+            /// Copy into an owned name.
+            pub fn to_buf(&self) -> $owned {
+                $owned(self.0.to_string())
+            }
+        }
+
+        impl fmt::Display for $borrowed<'_> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(self.0)
+            }
+        }
+
+        impl fmt::Display for $owned {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(&self.0)
+            }
+        }
+
+        impl FromStr for $owned {
+            type Err = Error;
+
+            fn from_str(s: &str) -> Result<Self> {
+                Self::new(s)
+            }
+        }
+
+        impl TryFrom<String> for $owned {
+            type Error = Error;
+
+            fn try_from(s: String) -> Result<Self> {
+                Self::new(s)
+            }
+        }
+
+        impl From<$owned> for String {
+            fn from(name: $owned) -> Self {
+                name.0
+            }
+        }
+
+        impl From<$borrowed<'_>> for $owned {
+            fn from(name: $borrowed<'_>) -> Self {
+                name.to_buf()
+            }
+        }
+
+        impl<'a> From<&'a $owned> for $borrowed<'a> {
+            fn from(name: &'a $owned) -> Self {
+                name.as_name()
+            }
+        }
+
+        impl AsRef<str> for $owned {
+            fn as_ref(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl Borrow<str> for $owned {
+            fn borrow(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl From<u32> for $reference<'_> {
+            fn from(id: u32) -> Self {
+                Self::Id(id)
+            }
+        }
+
+        impl<'a> From<$borrowed<'a>> for $reference<'a> {
+            fn from(name: $borrowed<'a>) -> Self {
+                Self::Name(name)
+            }
+        }
+
+        impl<'a> From<&'a $owned> for $reference<'a> {
+            fn from(name: &'a $owned) -> Self {
+                Self::Name(name.as_name())
+            }
+        }
+    };
+}
+
+owned_name!(PokemonNameBuf, PokemonName, PokemonRef, "pokemon_name");
+owned_name!(GenerationNameBuf, GenerationName, GenerationRef, "generation_name");
 
 fn validate_pokeapi_name(name: &str, field: &'static str) -> Result<()> {
     if name.is_empty() {
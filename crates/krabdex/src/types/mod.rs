@@ -1,5 +1,7 @@
 pub mod identifiers;
 pub mod pagination;
+pub mod normalize;
 
 pub use pagination::*;
-pub use identifiers::*;
\ No newline at end of file
+pub use identifiers::*;
+pub use normalize::*;
\ No newline at end of file
@@ -0,0 +1,151 @@
+use crate::{
+    error::{Error, Result},
+    types::identifiers::{PokemonName, PokemonNameBuf},
+};
+
+/// One kind of edit made by `PokemonName::normalize`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NameChange {
+    /// Leading/trailing whitespace removed.
+    Trimmed,
+    /// Uppercase letters lowercased.
+    Lowercased,
+    /// Accented letters replaced by their base letter (`é` → `e`).
+    DiacriticsRemoved,
+    /// `♀`/`♂` mapped to the `-f`/`-m` suffixes.
+    GenderSymbolMapped,
+    /// Spaces, underscores, or `.`/`:` between letters turned into hyphens.
+    SeparatorsReplaced,
+    /// Punctuation such as `.`, `'`, `’` or `:` dropped.
+    PunctuationRemoved,
+}
+
+/// Result of normalizing user input into a PokeAPI slug.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NormalizedName {
+    /// The slug, ready to pass to the client.
+    pub name: PokemonNameBuf,
+    /// The input exactly as given.
+    pub original: String,
+    /// Each kind of edit that was needed, in the order first applied.
+    pub changes: Vec<NameChange>,
+}
+
+impl NormalizedName {
+    /// Whether the input already was a valid slug.
+    pub fn is_unchanged(&self) -> bool {
+        self.changes.is_empty()
+    }
+}
+
+impl PokemonName<'_> {
+    /// Turn user-typed input ("Mr. Mime", "Farfetch’d", "Nidoran♀") into a
+    /// PokeAPI slug (`mr-mime`, `farfetchd`, `nidoran-f`), reporting what changed.
+    ///
+    /// Unlike `PokemonName::new` this is lenient; it still fails with
+    /// `InvalidArgument` on characters it cannot map (non-Latin letters, `ß`, `æ`, ...)
+    /// or if nothing valid is left.
+    pub fn normalize(input: &str) -> Result<NormalizedName> {
+        let mut changes = Vec::new();
+
+        let trimmed = input.trim();
+        if trimmed.len() != input.len() {
+            note(&mut changes, NameChange::Trimmed);
+        }
+
+        let chars: Vec<char> = trimmed.chars().collect();
+        let mut slug = String::with_capacity(trimmed.len());
+        for (i, &c) in chars.iter().enumerate() {
+            match c {
+                'a'..='z' | '0'..='9' | '-' => slug.push(c),
+                // "Mr.Mime", "Type:Null": a dot or colon inside a word separates words.
+                '.' | ':' if between_letters(&chars, i) => {
+                    note(&mut changes, NameChange::SeparatorsReplaced);
+                    slug.push('-');
+                }
+                'A'..='Z' => {
+                    note(&mut changes, NameChange::Lowercased);
+                    slug.push(c.to_ascii_lowercase());
+                }
+                '♀' => {
+                    note(&mut changes, NameChange::GenderSymbolMapped);
+                    slug.push_str("-f");
+                }
+                '♂' => {
+                    note(&mut changes, NameChange::GenderSymbolMapped);
+                    slug.push_str("-m");
+                }
+                c if c.is_whitespace() || c == '_' => {
+                    note(&mut changes, NameChange::SeparatorsReplaced);
+                    slug.push('-');
+                }
+                c => match strip_diacritic(c) {
+                    Some(base) => {
+                        note(&mut changes, NameChange::DiacriticsRemoved);
+                        if c.is_uppercase() {
+                            note(&mut changes, NameChange::Lowercased);
+                        }
+                        slug.push(base);
+                    }
+                    None if is_punctuation(c) => note(&mut changes, NameChange::PunctuationRemoved),
+                    None => {
+                        return Err(Error::InvalidArgument {
+                            field: "pokemon_name",
+                            reason: format!("cannot normalize character `{c}`"),
+                        });
+                    }
+                },
+            }
+        }
+
+        // "Nidoran ♀" becomes "nidoran--f"; collapse and trim the separators.
+        let collapsed = slug
+            .split('-')
+            .filter(|part| !part.is_empty())
+            .collect::<Vec<_>>()
+            .join("-");
+        if collapsed != slug && !changes.contains(&NameChange::GenderSymbolMapped) {
+            note(&mut changes, NameChange::SeparatorsReplaced);
+        }
+
+        Ok(NormalizedName {
+            name: PokemonNameBuf::new(collapsed)?,
+            original: input.to_string(),
+            changes,
+        })
+    }
+}
+
+/// Record `change` once, keeping first-seen order.
+fn note(changes: &mut Vec<NameChange>, change: NameChange) {
+    if !changes.contains(&change) {
+        changes.push(change);
+    }
+}
+
+/// Whether `chars[i]` has a letter on both sides.
+fn between_letters(chars: &[char], i: usize) -> bool {
+    let letter = |j: Option<usize>| j.and_then(|j| chars.get(j)).is_some_and(|c| c.is_alphabetic());
+    letter(i.checked_sub(1)) && letter(Some(i + 1))
+}
+
+/// Punctuation that is dropped from names (`Mr. Mime`, `Farfetch’d`, `Type: Null`).
+fn is_punctuation(c: char) -> bool {
+    c.is_ascii_punctuation() || matches!(c, '’' | '‘' | '“' | '”' | '′' | '…' | '«' | '»')
+}
+
+/// Base lowercase letter of a Latin accented letter.
+fn strip_diacritic(c: char) -> Option<char> {
+    let base = match c.to_lowercase().next()? {
+        'à' | 'á' | 'â' | 'ã' | 'ä' | 'å' => 'a',
+        'ç' | 'č' => 'c',
+        'è' | 'é' | 'ê' | 'ë' => 'e',
+        'ì' | 'í' | 'î' | 'ï' => 'i',
+        'ñ' => 'n',
+        'ò' | 'ó' | 'ô' | 'õ' | 'ö' => 'o',
+        'ù' | 'ú' | 'û' | 'ü' => 'u',
+        'ý' | 'ÿ' => 'y',
+        _ => return None,
+    };
+    Some(base)
+}
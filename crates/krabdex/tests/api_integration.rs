@@ -132,3 +132,417 @@ async fn pokemon_list_sets_limit_and_offset() {
     assert_eq!(page.results.len(), 2);
     assert_eq!(page.results[0].name, "foo");
 }
+
+#[tokio::test]
+async fn get_raw_and_get_typed_reach_unmodeled_endpoints() {
+    let server = MockServer::start();
+    let m = server.mock(|when, then| {
+        when.method(GET).path("/api/v2/berry/1").query_param("lang", "en");
+        then.status(200).body(r#"{"id": 1, "name": "cheri", "growth_time": 3}"#);
+    });
+
+    #[derive(serde::Deserialize)]
+    struct Berry {
+        name: String,
+        growth_time: u32,
+    }
+
+    let client = client_with_base(&server);
+    let mut q = krabdex::http::Query::new();
+    q.set("lang", "en");
+
+    let raw = client.get_raw("berry/1", Some(q.clone())).await.unwrap();
+    let typed: Berry = client.get_typed("berry/1", Some(q)).await.unwrap();
+
+    m.assert_calls(2);
+    assert_eq!(raw["name"], "cheri");
+    assert_eq!((typed.name.as_str(), typed.growth_time), ("cheri", 3));
+}
+
+#[tokio::test]
+async fn get_raw_classifies_errors_and_rejects_absolute_urls() {
+    let server = MockServer::start();
+    server.mock(|when, then| {
+        when.method(GET).path("/api/v2/berry/999");
+        then.status(404);
+    });
+
+    let client = client_with_base(&server);
+
+    let err = client.get_raw("berry/999", None).await.unwrap_err();
+    assert!(matches!(err, krabdex::Error::Api(ref api) if api.status == 404));
+
+    let err = client.get_raw("https://example.com/berry/1", None).await.unwrap_err();
+    assert!(matches!(err, krabdex::Error::InvalidArgument { field: "path", .. }));
+}
+
+#[tokio::test]
+async fn generation_fetched_keeps_raw_payload_and_headers() {
+    let server = MockServer::start();
+    let body = r#"{
+        "id": 1,
+        "name": "generation-i",
+        "abilities": [],
+        "moves": [],
+        "pokemon_species": [],
+        "types": [],
+        "version_groups": [],
+        "main_region": { "name": "kanto", "url": "https://pokeapi.co/api/v2/region/1/" },
+        "names": [],
+        "brand_new_field": { "nested": true }
+    }"#;
+    server.mock(|when, then| {
+        when.method(GET).path("/api/v2/generation/1");
+        then.status(200).header("etag", "\"gen1\"").body(body);
+    });
+
+    let client = client_with_base(&server);
+    let fetched = client.generation_fetched(GenerationRef::Id(1)).await.unwrap();
+
+    assert_eq!(fetched.value.name, "generation-i");
+    assert_eq!(fetched.raw, body.as_bytes());
+    assert_eq!(fetched.raw_json().unwrap()["brand_new_field"]["nested"], true);
+    assert_eq!(fetched.headers.get("etag"), Some("\"gen1\""));
+    assert_eq!(fetched.status, 200);
+    assert_eq!(fetched.cache_status, krabdex::cache::CacheStatus::Fetched);
+}
+
+#[tokio::test]
+async fn lenient_mode_repairs_and_reports_warnings() {
+    let server = MockServer::start();
+    server.mock(|when, then| {
+        when.method(GET).path("/api/v2/pokemon");
+        then.status(200).body(
+            r#"{"count": 1, "next": null, "previous": null, "shiny_new": true,
+                "results": [{"name":"a","url":"u"}, {"name": 7, "url":"u"}]}"#,
+        );
+    });
+
+    let builder = || {
+        PokeApiClient::builder().base_url(Url::parse(&server.base_url()).unwrap())
+    };
+    let page = PageRequest::first_page(Limit::DEFAULT);
+
+    let standard = builder().build().unwrap();
+    let err = standard.pokemon_list(page).await.unwrap_err();
+    assert!(matches!(err, krabdex::Error::Deserialize { .. }), "{err:?}");
+
+    let lenient = builder()
+        .deserialize_mode(krabdex::decode::DeserializeMode::Lenient)
+        .build()
+        .unwrap();
+    let fetched = lenient
+        .get_fetched::<krabdex::models::common::Page<krabdex::models::common::NamedApiResource>>("pokemon", None)
+        .await
+        .unwrap();
+    assert_eq!(fetched.value.results.len(), 1);
+    assert_eq!(fetched.warnings[0].path, "results[1].name");
+}
+
+#[tokio::test]
+async fn strict_mode_reports_unknown_fields() {
+    let server = MockServer::start();
+    server.mock(|when, then| {
+        when.method(GET).path("/api/v2/generation");
+        then.status(200).body(r#"{"count": 0, "next": null, "previous": null, "results": [], "shiny_new": 1}"#);
+    });
+
+    let client = PokeApiClient::builder()
+        .base_url(Url::parse(&server.base_url()).unwrap())
+        .deserialize_mode(krabdex::decode::DeserializeMode::Strict)
+        .build()
+        .unwrap();
+
+    match client.generation_list(PageRequest::first_page(Limit::DEFAULT)).await {
+        Err(krabdex::Error::UnknownFields { fields, .. }) => assert_eq!(fields, vec!["shiny_new"]),
+        other => panic!("expected UnknownFields, got {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn deserialize_errors_carry_path_snippet_and_type() {
+    let server = MockServer::start();
+    server.mock(|when, then| {
+        when.method(GET).path("/api/v2/generation");
+        then.status(200).body(
+            r#"{"count": 1, "next": null, "previous": null,
+                "results": [{"name": "generation-i", "url": 42}]}"#,
+        );
+    });
+
+    let client = client_with_base(&server);
+    let err = client
+        .generation_list(PageRequest::first_page(Limit::DEFAULT))
+        .await
+        .unwrap_err();
+
+    match &err {
+        krabdex::Error::Deserialize { path, snippet, type_name, .. } => {
+            assert_eq!(path, "results[0].url");
+            assert_eq!(snippet.as_deref(), Some("42"));
+            assert!(type_name.contains("Page<krabdex::models::common::NamedApiResource>"), "{type_name}");
+        }
+        other => panic!("unexpected error: {other:?}"),
+    }
+    assert!(err.to_string().contains("at `results[0].url`"), "{err}");
+}
+
+#[tokio::test]
+async fn not_found_names_the_resource_and_identifier() {
+    let server = MockServer::start();
+    server.mock(|when, then| {
+        when.method(GET).path("/api/v2/pokemon/pikachuu");
+        then.status(404).body("Not Found");
+    });
+    let list = server.mock(|when, then| {
+        when.method(GET).path("/api/v2/pokemon");
+        then.status(200).body("{}");
+    });
+
+    let client = client_with_base(&server);
+    let err = client
+        .pokemon(PokemonRef::Name(krabdex::types::PokemonName::new("pikachuu").unwrap()))
+        .await
+        .unwrap_err();
+
+    assert_eq!(err.to_string(), "api error: pokemon `pikachuu` not found (status 404)");
+    list.assert_calls(0);
+}
+
+#[tokio::test]
+async fn not_found_suggestions_come_from_the_name_index() {
+    let server = MockServer::start();
+    server.mock(|when, then| {
+        when.method(GET).path("/api/v2/pokemon/pikachuu");
+        then.status(404).body("Not Found");
+    });
+    server.mock(|when, then| {
+        when.method(GET).path("/api/v2/pokemon/9999");
+        then.status(404).body("Not Found");
+    });
+    let list = server.mock(|when, then| {
+        when.method(GET)
+            .path("/api/v2/pokemon")
+            .query_param("offset", "0");
+        then.status(200).body(
+            r#"{"count": 3, "next": null, "previous": null, "results": [
+                {"name": "pikachu", "url": "https://pokeapi.co/api/v2/pokemon/25/"},
+                {"name": "raichu", "url": "https://pokeapi.co/api/v2/pokemon/26/"},
+                {"name": "bulbasaur", "url": "https://pokeapi.co/api/v2/pokemon/1/"}
+            ]}"#,
+        );
+    });
+
+    let client = PokeApiClient::builder()
+        .base_url(Url::parse(&server.base_url()).unwrap())
+        .not_found_suggestions(true)
+        .build()
+        .unwrap();
+    let name = || PokemonRef::Name(krabdex::types::PokemonName::new("pikachuu").unwrap());
+
+    let err = client.pokemon(name()).await.unwrap_err();
+    match &err {
+        krabdex::Error::Api(api) => match &api.kind {
+            krabdex::error::ApiErrorKind::NotFound { resource, identifier, suggestions } => {
+                assert_eq!(*resource, "pokemon");
+                assert_eq!(identifier, "pikachuu");
+                assert_eq!(suggestions, &vec!["pikachu".to_string()]);
+            }
+            other => panic!("unexpected api kind: {other:?}"),
+        },
+        other => panic!("unexpected error: {other:?}"),
+    }
+    assert!(err.to_string().ends_with("did you mean `pikachu`?"), "{err}");
+
+    // The index is loaded once; numeric ids never trigger it.
+    client.pokemon(name()).await.unwrap_err();
+    client.pokemon(PokemonRef::Id(9999)).await.unwrap_err();
+    list.assert_calls(1);
+}
+
+#[tokio::test]
+async fn owned_names_and_ids_are_accepted_directly() {
+    let server = MockServer::start();
+    let by_name = server.mock(|when, then| {
+        when.method(GET).path("/api/v2/generation/generation-i");
+        then.status(404);
+    });
+    let by_id = server.mock(|when, then| {
+        when.method(GET).path("/api/v2/generation/1");
+        then.status(404);
+    });
+
+    let client = client_with_base(&server);
+    let name = krabdex::types::GenerationNameBuf::new("generation-i").unwrap();
+
+    assert!(client.generation(&name).await.unwrap_err().is_not_found());
+    assert!(client.generation_by_name(&name).await.unwrap_err().is_not_found());
+    assert!(client.generation(1).await.unwrap_err().is_not_found());
+
+    by_name.assert_calls(2);
+    by_id.assert_calls(1);
+}
+
+#[tokio::test]
+async fn name_index_walks_every_page_once() {
+    let server = MockServer::start();
+    let first = server.mock(|when, then| {
+        when.method(GET)
+            .path("/api/v2/pokemon")
+            .query_param("limit", "100")
+            .query_param("offset", "0");
+        then.status(200).body(
+            r#"{"count": 3, "next": "https://pokeapi.co/api/v2/pokemon?offset=2&limit=100", "previous": null,
+                "results": [
+                    {"name": "bulbasaur", "url": "https://pokeapi.co/api/v2/pokemon/1/"},
+                    {"name": "ivysaur", "url": "https://pokeapi.co/api/v2/pokemon/2/"}
+                ]}"#,
+        );
+    });
+    let second = server.mock(|when, then| {
+        when.method(GET)
+            .path("/api/v2/pokemon")
+            .query_param("limit", "100")
+            .query_param("offset", "2");
+        then.status(200).body(
+            r#"{"count": 3, "next": null, "previous": null,
+                "results": [{"name": "venusaur", "url": "https://pokeapi.co/api/v2/pokemon/3/"}]}"#,
+        );
+    });
+
+    let client = client_with_base(&server);
+    let index = client.name_index("pokemon").await.unwrap();
+    assert_eq!(index.names(), ["bulbasaur", "ivysaur", "venusaur"]);
+
+    let hits = client.clone().search_pokemon("saur", 5).await.unwrap();
+    let names: Vec<&str> = hits.iter().map(|m| m.name.as_str()).collect();
+    assert_eq!(names, ["ivysaur", "venusaur", "bulbasaur"]);
+
+    first.assert_calls(1);
+    second.assert_calls(1);
+}
+
+#[tokio::test]
+async fn concurrent_first_name_index_calls_share_one_load() {
+    let server = MockServer::start();
+    let list = server.mock(|when, then| {
+        when.method(GET).path("/api/v2/generation");
+        then.status(200)
+            .delay(std::time::Duration::from_millis(100))
+            .body(
+                r#"{"count": 1, "next": null, "previous": null,
+                    "results": [{"name": "generation-i", "url": "https://pokeapi.co/api/v2/generation/1/"}]}"#,
+            );
+    });
+
+    let client = client_with_base(&server);
+    let (a, b) = tokio::join!(client.name_index("generation"), client.name_index("generation"));
+
+    assert!(std::sync::Arc::ptr_eq(&a.unwrap(), &b.unwrap()));
+    list.assert_calls(1);
+}
+
+#[tokio::test]
+async fn name_index_reports_malformed_list_pages_as_deserialize_errors() {
+    let server = MockServer::start();
+    server.mock(|when, then| {
+        when.method(GET).path("/api/v2/generation");
+        then.status(200).body(r#"{"count": 1, "next": null, "previous": null, "results": [{"name": 7}]}"#);
+    });
+
+    let err = client_with_base(&server).name_index("generation").await.unwrap_err();
+
+    match err {
+        krabdex::Error::Deserialize { url, path, .. } => {
+            assert!(url.ends_with("/api/v2/generation"), "{url}");
+            assert_eq!(path, "results[0].name");
+        }
+        other => panic!("expected a deserialize error, got {other:?}"),
+    }
+}
+
+#[test]
+fn resource_links_expose_kind_and_id() {
+    use krabdex::models::common::NamedApiResource;
+
+    let pikachu = NamedApiResource {
+        name: "pikachu".into(),
+        url: "https://pokeapi.co/api/v2/pokemon/25/".into(),
+    };
+    assert_eq!(pikachu.id(), Some(25));
+    assert_eq!(pikachu.resource_kind(), Some("pokemon"));
+
+    let not_numeric = NamedApiResource { name: "x".into(), url: "https://pokeapi.co/api/v2/pokemon/x".into() };
+    assert_eq!(not_numeric.id(), None);
+    assert_eq!(not_numeric.resource_kind(), None);
+
+    let encounters = krabdex::models::common::ApiResource {
+        url: "https://pokeapi.co/api/v2/pokemon/25/encounters".into(),
+    };
+    assert_eq!(encounters.id(), None);
+    assert_eq!(encounters.resource_kind(), None);
+
+    let chain = krabdex::models::common::ApiResource {
+        url: "https://pokeapi.co/api/v2/evolution-chain/10/".into(),
+    };
+    assert_eq!(chain.id(), Some(10));
+    assert_eq!(chain.resource_kind(), Some("evolution-chain"));
+
+    let mirrored = NamedApiResource {
+        name: "pikachu".into(),
+        url: "http://localhost:8000/pokeapi/v3/pokemon/25".into(),
+    };
+    assert_eq!(mirrored.id(), Some(25));
+    assert_eq!(mirrored.resource_kind(), Some("pokemon"));
+
+    let nested = NamedApiResource {
+        name: "bulbasaur".into(),
+        url: "https://example.com/api/v2/proxy/api/v2/pokemon/1/".into(),
+    };
+    assert_eq!(nested.id(), Some(1));
+    assert_eq!(nested.resource_kind(), Some("pokemon"));
+}
+
+#[test]
+fn resource_refs_are_validated_against_the_configured_instance() {
+    use krabdex::models::common::NamedApiResource;
+
+    let mirror = PokeApiClient::builder()
+        .base_url(Url::parse("http://localhost:8000/pokeapi/").unwrap())
+        .build()
+        .unwrap();
+    let link = |url: &str| NamedApiResource { name: "n".into(), url: url.into() };
+
+    let id = mirror.resource_id("http://localhost:8000/pokeapi/api/v2/pokemon/25/").unwrap();
+    assert_eq!((id.kind.as_str(), id.id), ("pokemon", 25));
+
+    let r = mirror.pokemon_ref(&link("http://localhost:8000/pokeapi/api/v2/pokemon/25/")).unwrap();
+    assert!(matches!(r, PokemonRef::Id(25)));
+    let g = mirror.generation_ref(&link("http://localhost:8000/pokeapi/api/v2/generation/1/")).unwrap();
+    assert!(matches!(g, GenerationRef::Id(1)));
+
+    for url in [
+        "https://pokeapi.co/api/v2/pokemon/25/",
+        "http://localhost:8000/pokeapi/api/v2/ability/9/",
+        "http://localhost:8000/pokeapi/api/v2/pokemon/pikachu/",
+    ] {
+        let err = mirror.pokemon_ref(&link(url)).unwrap_err();
+        assert!(matches!(err, krabdex::Error::InvalidArgument { field: "url", .. }), "{url}: {err}");
+    }
+}
+
+#[tokio::test]
+async fn tls_failures_are_classified_and_not_retried() {
+    let server = MockServer::start();
+    let https = server.base_url().replacen("http://", "https://", 1);
+
+    let client = PokeApiClient::builder()
+        .base_url(Url::parse(&https).unwrap())
+        .max_retries(2)
+        .build()
+        .unwrap();
+    let err = client.pokemon(PokemonRef::Id(1)).await.unwrap_err();
+
+    assert_eq!(err.transport_kind(), Some(krabdex::error::TransportErrorKind::Tls), "{err:?}");
+    assert!(!err.is_retryable());
+}
@@ -0,0 +1,126 @@
+use httpmock::prelude::*;
+use krabdex::{assets::{AssetKind, AssetStore}, PokeApiClient};
+use url::Url;
+
+const PNG: &[u8] = b"\x89PNG\r\n\x1a\n\0\0\0\rIHDR";
+const OGG: &[u8] = b"OggS\0\x02\0\0\0\0";
+
+fn client_with_base(server: &MockServer) -> PokeApiClient {
+    PokeApiClient::builder()
+        .base_url(Url::parse(&server.base_url()).unwrap())
+        .default_header("authorization", "Bearer api-only")
+        .build()
+        .unwrap()
+}
+
+#[tokio::test]
+async fn fetch_asset_verifies_bytes_not_headers() {
+    let server = MockServer::start();
+    let m = server.mock(|when, then| {
+        when.method(GET).path("/sprites/25.png").header("accept", "image/*");
+        then.status(200).header("content-type", "text/plain; charset=utf-8").body(PNG);
+    });
+
+    let client = client_with_base(&server);
+    let asset = client.fetch_asset(&server.url("/sprites/25.png")).await.unwrap();
+
+    m.assert();
+    assert_eq!(asset.kind, AssetKind::Image);
+    assert_eq!(asset.content_type, "image/png");
+    assert_eq!(asset.bytes, PNG);
+}
+
+#[tokio::test]
+async fn default_headers_are_not_sent_to_asset_hosts() {
+    let server = MockServer::start();
+    let leaked = server.mock(|when, then| {
+        when.method(GET).path("/cries/25.ogg").header_exists("authorization");
+        then.status(500);
+    });
+    server.mock(|when, then| {
+        when.method(GET).path("/cries/25.ogg");
+        then.status(200).header("content-type", "application/octet-stream").body(OGG);
+    });
+
+    let client = client_with_base(&server);
+    let asset = client.fetch_asset(&server.url("/cries/25.ogg")).await.unwrap();
+
+    assert_eq!(asset.kind, AssetKind::Audio);
+    leaked.assert_calls(0);
+}
+
+#[tokio::test]
+async fn fetch_asset_rejects_non_media_and_mismatched_kinds() {
+    let server = MockServer::start();
+    server.mock(|when, then| {
+        when.method(GET).path("/sprites/1.png");
+        then.status(200).header("content-type", "text/html").body("<html>rate limited</html>");
+    });
+    server.mock(|when, then| {
+        when.method(GET).path("/cries/1.ogg");
+        then.status(200).body(PNG);
+    });
+
+    let client = client_with_base(&server);
+
+    let err = client.fetch_asset(&server.url("/sprites/1.png")).await.unwrap_err();
+    assert!(
+        matches!(&err, krabdex::Error::UnexpectedContentType { content_type: Some(ct), .. } if ct == "text/html"),
+        "{err:?}"
+    );
+
+    let err = client.fetch_asset(&server.url("/cries/1.ogg")).await.unwrap_err();
+    assert!(matches!(err, krabdex::Error::UnexpectedContentType { .. }), "{err:?}");
+
+    let err = client.fetch_asset("sprites/1.png").await.unwrap_err();
+    assert!(matches!(err, krabdex::Error::InvalidArgument { field: "url", .. }));
+}
+
+#[tokio::test]
+async fn fetch_asset_retries_and_classifies_http_errors() {
+    let server = MockServer::start();
+    let missing = server.mock(|when, then| {
+        when.method(GET).path("/sprites/0.png");
+        then.status(404);
+    });
+    let flaky = server.mock(|when, then| {
+        when.method(GET).path("/sprites/2.png");
+        then.status(503).header("retry-after", "0");
+    });
+
+    let client = PokeApiClient::builder()
+        .base_url(Url::parse(&server.base_url()).unwrap())
+        .max_retries(2)
+        .build()
+        .unwrap();
+
+    let err = client.fetch_asset(&server.url("/sprites/0.png")).await.unwrap_err();
+    assert!(err.is_not_found());
+    missing.assert_calls(1);
+
+    let err = client.fetch_asset(&server.url("/sprites/2.png")).await.unwrap_err();
+    assert_eq!(err.status(), Some(503));
+    flaky.assert_calls(3);
+}
+
+#[tokio::test]
+async fn download_asset_writes_into_the_store() {
+    let server = MockServer::start();
+    server.mock(|when, then| {
+        when.method(GET).path("/sprites/25.png");
+        then.status(200).header("content-type", "image/png").body(PNG);
+    });
+
+    let dir = tempfile::tempdir().unwrap();
+    let store = AssetStore::new(dir.path().join("assets"));
+    let client = client_with_base(&server);
+
+    let stored = client
+        .download_asset(&server.url("/sprites/25.png"), &store)
+        .await
+        .unwrap();
+
+    assert!(stored.path.starts_with(store.root()));
+    assert_eq!(stored.path.extension().unwrap(), "png");
+    assert_eq!(std::fs::read(&stored.path).unwrap(), PNG);
+}
@@ -0,0 +1,49 @@
+#![cfg(feature = "blocking")]
+
+use httpmock::prelude::*;
+use krabdex::{
+    blocking::PokeApiClient,
+    types::{Limit, PageRequest},
+};
+use url::Url;
+
+fn client_with_base(server: &MockServer) -> PokeApiClient {
+    krabdex::PokeApiClient::builder()
+        .base_url(Url::parse(&server.base_url()).unwrap())
+        .build_blocking()
+        .unwrap()
+}
+
+#[test]
+fn blocking_list_drives_the_async_client() {
+    let server = MockServer::start();
+    let m = server.mock(|when, then| {
+        when.method(GET).path("/api/v2/generation").query_param("limit", "1");
+        then.status(200).body(
+            r#"{"count": 9, "next": null, "previous": null,
+                "results": [{"name":"generation-i","url":"https://pokeapi.co/api/v2/generation/1/"}]}"#,
+        );
+    });
+
+    let client = client_with_base(&server);
+    let page = client
+        .generation_list(PageRequest::first_page(Limit::new(1).unwrap()))
+        .unwrap();
+
+    m.assert();
+    assert_eq!(page.count, 9);
+    assert_eq!(page.results[0].name, "generation-i");
+}
+
+#[test]
+fn blocking_errors_are_classified() {
+    let server = MockServer::start();
+    server.mock(|when, then| {
+        when.method(GET).path("/api/v2/pokemon/0");
+        then.status(404);
+    });
+
+    let err = client_with_base(&server).pokemon_by_id(0).unwrap_err();
+
+    assert!(matches!(err, krabdex::Error::Api(ref api) if api.status == 404));
+}
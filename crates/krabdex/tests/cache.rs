@@ -0,0 +1,141 @@
+use std::time::Duration;
+
+use httpmock::prelude::*;
+use krabdex::{
+    cache::{CachePolicy, CacheStats},
+    types::{Limit, PageRequest},
+    PokeApiClient,
+};
+use url::Url;
+
+const PAGE: &str = r#"{
+    "count": 1,
+    "next": null,
+    "previous": null,
+    "results": [{"name":"bulbasaur","url":"https://pokeapi.co/api/v2/pokemon/1/"}]
+}"#;
+
+fn cached_client(server: &MockServer, ttl: Duration) -> PokeApiClient {
+    PokeApiClient::builder()
+        .base_url(Url::parse(&server.base_url()).unwrap())
+        .cache_ttl(ttl)
+        .build()
+        .unwrap()
+}
+
+fn first_page() -> PageRequest {
+    PageRequest::first_page(Limit::new(1).unwrap())
+}
+
+#[tokio::test]
+async fn fresh_entries_skip_the_network() {
+    let server = MockServer::start();
+    let m = server.mock(|when, then| {
+        when.method(GET).path("/api/v2/pokemon");
+        then.status(200).body(PAGE);
+    });
+
+    let client = cached_client(&server, Duration::from_secs(60));
+    let a = client.pokemon_list(first_page()).await.unwrap();
+    let b = client.pokemon_list(first_page()).await.unwrap();
+
+    m.assert_calls(1);
+    assert_eq!(a, b);
+    assert_eq!(
+        client.cache_stats(),
+        Some(CacheStats { fresh: 1, revalidated: 0, fetched: 1 })
+    );
+}
+
+#[tokio::test]
+async fn expired_entries_are_revalidated_with_etag() {
+    let server = MockServer::start();
+    let initial = server.mock(|when, then| {
+        when.method(GET).path("/api/v2/pokemon").header_missing("if-none-match");
+        then.status(200).header("etag", "\"v1\"").body(PAGE);
+    });
+    let revalidate = server.mock(|when, then| {
+        when.method(GET).path("/api/v2/pokemon").header("if-none-match", "\"v1\"");
+        then.status(304);
+    });
+
+    let client = cached_client(&server, Duration::ZERO);
+    let a = client.pokemon_list(first_page()).await.unwrap();
+    let b = client.pokemon_list(first_page()).await.unwrap();
+
+    initial.assert_calls(1);
+    revalidate.assert_calls(1);
+    assert_eq!(a, b);
+    assert_eq!(
+        client.cache_stats(),
+        Some(CacheStats { fresh: 0, revalidated: 1, fetched: 1 })
+    );
+}
+
+#[tokio::test]
+async fn expired_entries_are_revalidated_with_last_modified() {
+    let server = MockServer::start();
+    let last_modified = "Wed, 21 Oct 2015 07:28:00 GMT";
+    server.mock(|when, then| {
+        when.method(GET).path("/api/v2/pokemon").header_missing("if-modified-since");
+        then.status(200).header("last-modified", last_modified).body(PAGE);
+    });
+    let revalidate = server.mock(|when, then| {
+        when.method(GET).path("/api/v2/pokemon").header("if-modified-since", last_modified);
+        then.status(304);
+    });
+
+    let client = cached_client(&server, Duration::ZERO);
+    client.pokemon_list(first_page()).await.unwrap();
+    client.pokemon_list(first_page()).await.unwrap();
+
+    revalidate.assert_calls(1);
+}
+
+#[tokio::test]
+async fn uncached_client_reports_no_stats() {
+    let client = PokeApiClient::new().unwrap();
+    assert_eq!(client.cache_stats(), None);
+}
+
+#[tokio::test]
+async fn server_directed_policy_uses_max_age() {
+    let server = MockServer::start();
+    let m = server.mock(|when, then| {
+        when.method(GET).path("/api/v2/pokemon");
+        then.status(200).header("cache-control", "max-age=600").body(PAGE);
+    });
+
+    // Fallback TTL of zero would force a refetch; max-age keeps the entry fresh.
+    let client = PokeApiClient::builder()
+        .base_url(Url::parse(&server.base_url()).unwrap())
+        .cache_ttl(Duration::ZERO)
+        .cache_policy(CachePolicy::ServerDirected)
+        .build()
+        .unwrap();
+
+    client.pokemon_list(first_page()).await.unwrap();
+    client.pokemon_list(first_page()).await.unwrap();
+
+    m.assert_calls(1);
+}
+
+#[tokio::test]
+async fn clones_share_the_cache() {
+    let server = MockServer::start();
+    let m = server.mock(|when, then| {
+        when.method(GET).path("/api/v2/pokemon");
+        then.status(200).body(PAGE);
+    });
+
+    let client = cached_client(&server, Duration::from_secs(60));
+    let clone = client.clone();
+
+    tokio::spawn(async move { clone.pokemon_list(first_page()).await.unwrap() })
+        .await
+        .unwrap();
+    client.pokemon_list(first_page()).await.unwrap();
+
+    m.assert_calls(1);
+    assert_eq!(client.cache_stats().unwrap().fresh, 1);
+}
@@ -0,0 +1,170 @@
+use httpmock::prelude::*;
+use krabdex::{models::names::TypeName, types::PokemonName, PokeApiClient};
+use serde_json::json;
+use url::Url;
+
+fn client_with_base(server: &MockServer) -> PokeApiClient {
+    PokeApiClient::builder()
+        .base_url(Url::parse(&server.base_url()).unwrap())
+        .build()
+        .unwrap()
+}
+
+fn link(kind: &str, name: &str, id: u32) -> serde_json::Value {
+    json!({ "name": name, "url": format!("https://pokeapi.co/api/v2/{kind}/{id}/") })
+}
+
+fn pokemon_json(id: u32, name: &str, is_default: bool, types: &[&str]) -> serde_json::Value {
+    let types: Vec<_> = types
+        .iter()
+        .enumerate()
+        .map(|(i, ty)| json!({ "slot": i + 1, "type": { "name": ty, "url": "" } }))
+        .collect();
+    json!({
+        "id": id,
+        "name": name,
+        "base_experience": null,
+        "height": 17,
+        "weight": 905,
+        "is_default": is_default,
+        "order": id,
+        "abilities": [],
+        "forms": [link("pokemon-form", name, id)],
+        "game_indices": [],
+        "held_items": [],
+        "location_area_encounters": "",
+        "moves": [],
+        "species": link("pokemon-species", "charizard", 6),
+        "stats": [],
+        "types": types,
+        "past_types": [],
+        "past_abilities": [],
+        "sprites": {},
+        "cries": null
+    })
+}
+
+fn species_json() -> serde_json::Value {
+    json!({
+        "id": 6,
+        "name": "charizard",
+        "order": 7,
+        "gender_rate": 1,
+        "capture_rate": 45,
+        "base_happiness": 50,
+        "is_baby": false,
+        "is_legendary": false,
+        "is_mythical": false,
+        "hatch_counter": 20,
+        "has_gender_differences": false,
+        "forms_switchable": true,
+        "growth_rate": link("growth-rate", "medium-slow", 4),
+        "pokedex_numbers": [{ "entry_number": 6, "pokedex": link("pokedex", "national", 1) }],
+        "egg_groups": [link("egg-group", "monster", 1), link("egg-group", "dragon", 14)],
+        "color": link("pokemon-color", "red", 8),
+        "shape": link("pokemon-shape", "upright", 6),
+        "evolves_from_species": link("pokemon-species", "charmeleon", 5),
+        "evolution_chain": { "url": "https://pokeapi.co/api/v2/evolution-chain/2/" },
+        "habitat": null,
+        "generation": link("generation", "generation-i", 1),
+        "names": [],
+        "varieties": [
+            { "is_default": false, "pokemon": link("pokemon", "charizard-mega-x", 10034) },
+            { "is_default": true, "pokemon": link("pokemon", "charizard", 6) },
+            { "is_default": false, "pokemon": link("pokemon", "charizard-gmax", 10196) }
+        ]
+    })
+}
+
+#[tokio::test]
+async fn pokemon_form_exposes_mega_flags_and_form_types() {
+    let server = MockServer::start();
+    let m = server.mock(|when, then| {
+        when.method(GET).path("/api/v2/pokemon-form/charizard-mega-x");
+        then.status(200).json_body(json!({
+            "id": 10034,
+            "name": "charizard-mega-x",
+            "order": 9,
+            "form_order": 2,
+            "is_default": true,
+            "is_battle_only": true,
+            "is_mega": true,
+            "form_name": "mega-x",
+            "pokemon": link("pokemon", "charizard-mega-x", 10034),
+            "types": [
+                { "slot": 1, "type": { "name": "fire", "url": "" } },
+                { "slot": 2, "type": { "name": "dragon", "url": "" } }
+            ],
+            "sprites": { "front_default": "https://example.test/10034.png" },
+            "version_group": link("version-group", "x-y", 15),
+            "names": [],
+            "form_names": []
+        }));
+    });
+
+    let client = client_with_base(&server);
+    let form = client.pokemon_form(PokemonName::new("charizard-mega-x").unwrap()).await.unwrap();
+
+    m.assert();
+    assert!(form.is_mega && form.is_battle_only);
+    assert_eq!(form.form_name, "mega-x");
+    assert_eq!(form.primary_type(), Some(TypeName::Fire));
+    assert_eq!(form.secondary_type(), Some(TypeName::Dragon));
+    assert_eq!(form.sprites.front_default.as_deref(), Some("https://example.test/10034.png"));
+}
+
+#[tokio::test]
+async fn species_varieties_resolve_to_pokemon_default_first() {
+    let server = MockServer::start();
+    let species = server.mock(|when, then| {
+        when.method(GET).path("/api/v2/pokemon-species/6");
+        then.status(200).json_body(species_json());
+    });
+    let variety = |id: u32, name: &'static str, is_default: bool, types: &'static [&'static str]| {
+        server.mock(move |when, then| {
+            when.method(GET).path(format!("/api/v2/pokemon/{name}"));
+            then.status(200).json_body(pokemon_json(id, name, is_default, types));
+        })
+    };
+    let base = variety(6, "charizard", true, &["fire", "flying"]);
+    let mega = variety(10034, "charizard-mega-x", false, &["fire", "dragon"]);
+    let gmax = variety(10196, "charizard-gmax", false, &["fire", "flying"]);
+
+    let client = client_with_base(&server);
+    let varieties = client.pokemon_varieties(6).await.unwrap();
+
+    let names: Vec<&str> = varieties.iter().map(|p| p.name.as_str()).collect();
+    assert_eq!(names, ["charizard", "charizard-mega-x", "charizard-gmax"]);
+    assert_eq!(varieties[1].secondary_type(), Some(TypeName::Dragon));
+
+    species.assert();
+    base.assert();
+    mega.assert();
+    gmax.assert();
+}
+
+#[tokio::test]
+async fn species_exposes_default_variety_and_missing_varieties_are_not_found() {
+    let server = MockServer::start();
+    server.mock(|when, then| {
+        when.method(GET).path("/api/v2/pokemon-species/charizard");
+        then.status(200).json_body(species_json());
+    });
+    server.mock(|when, then| {
+        when.method(GET).path("/api/v2/pokemon/charizard");
+        then.status(200).json_body(pokemon_json(6, "charizard", true, &["fire", "flying"]));
+    });
+    server.mock(|when, then| {
+        when.method(GET).path("/api/v2/pokemon/charizard-mega-x");
+        then.status(404);
+    });
+
+    let client = client_with_base(&server);
+    let species = client.pokemon_species(PokemonName::new("charizard").unwrap()).await.unwrap();
+    assert_eq!(species.default_variety().unwrap().name, "charizard");
+    assert_eq!(species.evolves_from_species.unwrap().name, "charmeleon");
+
+    let err = client.pokemon_varieties(PokemonName::new("charizard").unwrap()).await.unwrap_err();
+    assert!(err.is_not_found());
+    assert!(err.to_string().contains("pokemon `charizard-mega-x` not found"));
+}
@@ -0,0 +1,160 @@
+use std::{
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use httpmock::prelude::*;
+use krabdex::{
+    metrics::{MetricsObserver, RequestMetrics},
+    types::{Limit, PageRequest},
+    PokeApiClient,
+};
+use url::Url;
+
+#[derive(Debug, Clone, PartialEq)]
+struct Recorded {
+    endpoint: String,
+    status_class: Option<&'static str>,
+    attempts: u32,
+    bytes_received: u64,
+    cache_hit: bool,
+}
+
+#[derive(Default)]
+struct Recorder {
+    requests: Mutex<Vec<Recorded>>,
+    errors: Mutex<Vec<String>>,
+}
+
+impl MetricsObserver for Recorder {
+    fn on_request(&self, m: &RequestMetrics<'_>) {
+        self.requests.lock().unwrap().push(Recorded {
+            endpoint: m.endpoint.to_string(),
+            status_class: m.status_class(),
+            attempts: m.attempts,
+            bytes_received: m.bytes_received,
+            cache_hit: m.is_cache_hit(),
+        });
+    }
+
+    fn on_error(&self, endpoint: &str, error: &krabdex::Error) {
+        let kind = match error {
+            krabdex::Error::Api(api) => format!("{:?}", api.kind),
+            other => format!("{other:?}"),
+        };
+        self.errors.lock().unwrap().push(format!("{endpoint}: {kind}"));
+    }
+}
+
+const PAGE: &str = r#"{"count": 0, "next": null, "previous": null, "results": []}"#;
+
+#[tokio::test]
+async fn observer_sees_requests_cache_hits_and_errors() {
+    let server = MockServer::start();
+    server.mock(|when, then| {
+        when.method(GET).path("/api/v2/pokemon");
+        then.status(200).body(PAGE);
+    });
+    server.mock(|when, then| {
+        when.method(GET).path("/api/v2/generation/99");
+        then.status(404);
+    });
+
+    let recorder = Arc::new(Recorder::default());
+    let client = PokeApiClient::builder()
+        .base_url(Url::parse(&server.base_url()).unwrap())
+        .cache_ttl(Duration::from_secs(60))
+        .metrics(recorder.clone())
+        .build()
+        .unwrap();
+
+    let page = PageRequest::first_page(Limit::DEFAULT);
+    client.pokemon_list(page).await.unwrap();
+    client.pokemon_list(page).await.unwrap();
+    client.generation_by_id(99).await.unwrap_err();
+
+    let requests = recorder.requests.lock().unwrap();
+    assert_eq!(
+        *requests,
+        vec![
+            Recorded {
+                endpoint: "pokemon".into(),
+                status_class: Some("2xx"),
+                attempts: 1,
+                bytes_received: PAGE.len() as u64,
+                cache_hit: false,
+            },
+            Recorded {
+                endpoint: "pokemon".into(),
+                status_class: Some("2xx"),
+                attempts: 0,
+                bytes_received: 0,
+                cache_hit: true,
+            },
+            Recorded {
+                endpoint: "generation".into(),
+                status_class: Some("4xx"),
+                attempts: 1,
+                bytes_received: 0,
+                cache_hit: false,
+            },
+        ]
+    );
+
+    let errors = recorder.errors.lock().unwrap();
+    assert_eq!(errors.len(), 1);
+    assert!(errors[0].starts_with("generation: NotFound"), "{errors:?}");
+}
+
+#[tokio::test]
+async fn transport_failures_have_no_status() {
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let port = listener.local_addr().unwrap().port();
+    drop(listener);
+
+    let recorder = Arc::new(Recorder::default());
+    let client = PokeApiClient::builder()
+        .base_url(Url::parse(&format!("http://127.0.0.1:{port}/")).unwrap())
+        .max_retries(0)
+        .metrics(recorder.clone())
+        .build()
+        .unwrap();
+
+    client.pokemon_by_id(1).await.unwrap_err();
+
+    let requests = recorder.requests.lock().unwrap();
+    assert_eq!(requests[0].status_class, None);
+    assert_eq!(requests[0].attempts, 1);
+    assert_eq!(recorder.errors.lock().unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn name_index_pages_are_observed_like_other_requests() {
+    let server = MockServer::start();
+    server.mock(|when, then| {
+        when.method(GET).path("/api/v2/pokemon/pikachuu");
+        then.status(404);
+    });
+    server.mock(|when, then| {
+        when.method(GET).path("/api/v2/pokemon").query_param("limit", "100");
+        then.status(200).body(
+            r#"{"count": 1, "next": null, "previous": null,
+                "results": [{"name": "pikachu", "url": "https://pokeapi.co/api/v2/pokemon/25/"}]}"#,
+        );
+    });
+
+    let recorder = Arc::new(Recorder::default());
+    let client = PokeApiClient::builder()
+        .base_url(Url::parse(&server.base_url()).unwrap())
+        .not_found_suggestions(true)
+        .metrics(recorder.clone())
+        .build()
+        .unwrap();
+
+    let name = krabdex::types::PokemonName::new("pikachuu").unwrap();
+    client.pokemon_by_name(name).await.unwrap_err();
+
+    let requests = recorder.requests.lock().unwrap();
+    let seen: Vec<_> = requests.iter().map(|r| (r.endpoint.as_str(), r.status_class)).collect();
+    assert_eq!(seen, [("pokemon", Some("2xx")), ("pokemon", Some("4xx"))]);
+}
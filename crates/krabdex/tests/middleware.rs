@@ -0,0 +1,87 @@
+use std::{
+    sync::atomic::{AtomicU32, Ordering},
+    time::Duration,
+};
+
+use httpmock::prelude::*;
+use krabdex::{
+    client::retry::RetryConfig,
+    http::{Headers, HttpRequest, HttpResponse},
+    middleware::Middleware,
+    PokeApiClient,
+};
+use url::Url;
+
+const GENERATION: &str = r#"{
+    "id": 1,
+    "name": "generation-i",
+    "abilities": [],
+    "moves": [],
+    "pokemon_species": [],
+    "types": [],
+    "version_groups": [],
+    "main_region": { "name": "kanto", "url": "https://pokeapi.co/api/v2/region/1/" },
+    "names": []
+}"#;
+
+struct InjectHeader;
+
+impl Middleware for InjectHeader {
+    fn on_request(&self, req: &mut HttpRequest) -> krabdex::Result<Option<HttpResponse>> {
+        req.headers.insert("x-injected", "yes");
+        Ok(None)
+    }
+}
+
+/// Fails the first `n` attempts with a 503 without touching the network.
+struct FailFirst(AtomicU32);
+
+impl Middleware for FailFirst {
+    fn on_request(&self, _req: &mut HttpRequest) -> krabdex::Result<Option<HttpResponse>> {
+        if self.0.fetch_update(Ordering::SeqCst, Ordering::SeqCst, |n| n.checked_sub(1)).is_ok() {
+            return Ok(Some(HttpResponse {
+                status: 503,
+                headers: Headers::new(),
+                body: Vec::new(),
+            }));
+        }
+        Ok(None)
+    }
+}
+
+struct RenameRegion;
+
+impl Middleware for RenameRegion {
+    fn on_response(&self, _req: &HttpRequest, resp: &mut HttpResponse) -> krabdex::Result<()> {
+        let body = String::from_utf8_lossy(&resp.body).replace("kanto", "rewritten");
+        resp.body = body.into_bytes();
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn middleware_injects_headers_rewrites_responses_and_faults_are_retried() {
+    let server = MockServer::start();
+    let m = server.mock(|when, then| {
+        when.method(GET).path("/api/v2/generation/1").header("x-injected", "yes");
+        then.status(200).body(GENERATION);
+    });
+
+    let client = PokeApiClient::builder()
+        .base_url(Url::parse(&server.base_url()).unwrap())
+        .retry(RetryConfig {
+            max_retries: 2,
+            initial_backoff: Duration::from_millis(1),
+            max_backoff: Duration::from_millis(1),
+        })
+        .middleware(RenameRegion)
+        .middleware(InjectHeader)
+        .middleware(FailFirst(AtomicU32::new(1)))
+        .build()
+        .unwrap();
+
+    let gen = client.generation_by_id(1).await.unwrap();
+
+    m.assert_calls(1);
+    assert_eq!(gen.main_region.name, "rewritten");
+}
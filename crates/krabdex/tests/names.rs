@@ -0,0 +1,71 @@
+use krabdex::models::{
+    common::NamedApiResource,
+    names::{MoveDamageClass, MoveLearnMethod, StatName, TypeName, VersionGroupName},
+    pokemon::{MoveVersionGroupDetail, PokemonStat, PokemonTypeSlot},
+};
+
+fn link(name: &str) -> NamedApiResource {
+    NamedApiResource {
+        name: name.into(),
+        url: format!("https://pokeapi.co/api/v2/x/{name}/"),
+    }
+}
+
+#[test]
+fn known_names_parse_and_display_as_slugs() {
+    assert_eq!(TypeName::from("fire"), TypeName::Fire);
+    assert_eq!(StatName::from("special-attack"), StatName::SpecialAttack);
+    assert_eq!("physical".parse::<MoveDamageClass>().unwrap(), MoveDamageClass::Physical);
+    assert_eq!(VersionGroupName::from("x-y"), VersionGroupName::XY);
+    assert_eq!(MoveLearnMethod::LevelUp.to_string(), "level-up");
+
+    for stat in StatName::ALL {
+        assert_eq!(&StatName::from(stat.as_str()), stat);
+    }
+}
+
+#[test]
+fn unrecognised_names_fall_back_to_unknown() {
+    let future = TypeName::from("cosmic");
+    assert_eq!(future, TypeName::Unknown("cosmic".into()));
+    assert!(future.is_unknown());
+    assert_eq!(future.as_str(), "cosmic");
+    assert_eq!(TypeName::from("unknown").as_str(), "unknown");
+}
+
+#[test]
+fn hand_built_unknown_names_compare_and_hash_by_slug() {
+    use std::collections::HashSet;
+
+    assert_eq!(TypeName::Unknown("fire".into()), TypeName::Fire);
+    assert_ne!(TypeName::Unknown("fyre".into()), TypeName::Fire);
+
+    let types: HashSet<_> = [TypeName::Fire, TypeName::Unknown("fire".into())].into_iter().collect();
+    assert_eq!(types.len(), 1);
+}
+
+#[test]
+fn known_names_round_trip_through_serde() {
+    let json = serde_json::to_string(&[StatName::Hp, StatName::Unknown("luck".into())]).unwrap();
+    assert_eq!(json, r#"["hp","luck"]"#);
+
+    let back: Vec<StatName> = serde_json::from_str(&json).unwrap();
+    assert_eq!(back, [StatName::Hp, StatName::Unknown("luck".into())]);
+}
+
+#[test]
+fn model_accessors_return_typed_names() {
+    let slot = PokemonTypeSlot { slot: 1, ty: link("electric") };
+    assert_eq!(slot.type_name(), TypeName::Electric);
+
+    let stat = PokemonStat { base_stat: 90, effort: 2, stat: link("speed") };
+    assert_eq!(stat.stat_name(), StatName::Speed);
+
+    let detail = MoveVersionGroupDetail {
+        level_learned_at: 0,
+        move_learn_method: link("machine"),
+        version_group: link("scarlet-violet"),
+    };
+    assert_eq!(detail.learn_method(), MoveLearnMethod::Machine);
+    assert_eq!(detail.version_group_name(), VersionGroupName::ScarletViolet);
+}
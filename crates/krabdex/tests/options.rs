@@ -0,0 +1,151 @@
+use std::time::Duration;
+
+use httpmock::prelude::*;
+use krabdex::{
+    cache::CacheMode,
+    client::{options::RequestOptions, retry::RetryConfig},
+    types::{Limit, PageRequest},
+    PokeApiClient,
+};
+use url::Url;
+
+const PAGE: &str = r#"{"count": 0, "next": null, "previous": null, "results": []}"#;
+
+fn client_with_base(server: &MockServer) -> PokeApiClient {
+    PokeApiClient::builder()
+        .base_url(Url::parse(&server.base_url()).unwrap())
+        .cache_ttl(Duration::from_secs(60))
+        .retry(RetryConfig {
+            max_retries: 2,
+            initial_backoff: Duration::from_millis(1),
+            max_backoff: Duration::from_millis(5),
+        })
+        .build()
+        .unwrap()
+}
+
+fn first_page() -> PageRequest {
+    PageRequest::first_page(Limit::DEFAULT)
+}
+
+#[tokio::test]
+async fn options_add_headers_to_the_request() {
+    let server = MockServer::start();
+    let m = server.mock(|when, then| {
+        when.method(GET).path("/api/v2/pokemon").header("x-request-id", "abc123");
+        then.status(200).body(PAGE);
+    });
+
+    let client = client_with_base(&server);
+    client
+        .with_options(RequestOptions::new().header("x-request-id", "abc123"))
+        .pokemon_list(first_page())
+        .await
+        .unwrap();
+
+    m.assert();
+}
+
+#[tokio::test]
+async fn options_timeout_overrides_client_timeout() {
+    let server = MockServer::start();
+    server.mock(|when, then| {
+        when.method(GET).path("/api/v2/pokemon");
+        then.status(200).delay(Duration::from_millis(500)).body(PAGE);
+    });
+
+    let client = client_with_base(&server);
+    let err = client
+        .with_options(RequestOptions::new().timeout(Duration::from_millis(50)).no_retry())
+        .pokemon_list(first_page())
+        .await
+        .unwrap_err();
+
+    assert_eq!(err.transport_kind(), Some(krabdex::error::TransportErrorKind::Timeout), "got {err:?}");
+    assert!(err.is_retryable());
+}
+
+#[tokio::test]
+async fn bypass_skips_the_cache() {
+    let server = MockServer::start();
+    let m = server.mock(|when, then| {
+        when.method(GET).path("/api/v2/pokemon");
+        then.status(200).body(PAGE);
+    });
+
+    let client = client_with_base(&server);
+    let bypass = client.with_options(RequestOptions::new().cache_mode(CacheMode::Bypass));
+    bypass.pokemon_list(first_page()).await.unwrap();
+    bypass.pokemon_list(first_page()).await.unwrap();
+
+    m.assert_calls(2);
+    assert_eq!(client.cache_stats().unwrap().fetched, 0);
+}
+
+#[tokio::test]
+async fn force_refresh_refetches_and_stores() {
+    let server = MockServer::start();
+    let m = server.mock(|when, then| {
+        when.method(GET).path("/api/v2/pokemon");
+        then.status(200).body(PAGE);
+    });
+
+    let client = client_with_base(&server);
+    client.pokemon_list(first_page()).await.unwrap();
+    client
+        .with_options(RequestOptions::new().cache_mode(CacheMode::ForceRefresh))
+        .pokemon_list(first_page())
+        .await
+        .unwrap();
+    client.pokemon_list(first_page()).await.unwrap();
+
+    m.assert_calls(2);
+    assert_eq!(client.cache_stats().unwrap().fresh, 1);
+}
+
+#[tokio::test]
+async fn retryable_statuses_are_retried() {
+    let server = MockServer::start();
+    let m = server.mock(|when, then| {
+        when.method(GET).path("/api/v2/pokemon");
+        then.status(503);
+    });
+
+    let client = client_with_base(&server);
+    let err = client.pokemon_list(first_page()).await.unwrap_err();
+
+    m.assert_calls(3);
+    assert!(matches!(err, krabdex::Error::Api(ref api) if api.status == 503));
+}
+
+#[tokio::test]
+async fn no_retry_sends_once() {
+    let server = MockServer::start();
+    let m = server.mock(|when, then| {
+        when.method(GET).path("/api/v2/pokemon");
+        then.status(503);
+    });
+
+    let client = client_with_base(&server);
+    client
+        .with_options(RequestOptions::new().no_retry())
+        .pokemon_list(first_page())
+        .await
+        .unwrap_err();
+
+    m.assert_calls(1);
+}
+
+#[tokio::test]
+async fn not_found_is_not_retried() {
+    let server = MockServer::start();
+    let m = server.mock(|when, then| {
+        when.method(GET).path("/api/v2/pokemon/0");
+        then.status(404);
+    });
+
+    let client = client_with_base(&server);
+    client.pokemon_by_id(0).await.unwrap_err();
+
+    m.assert_calls(1);
+}
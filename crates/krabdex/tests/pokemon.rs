@@ -0,0 +1,176 @@
+use krabdex::models::{
+    names::{StatName, TypeName},
+    pokemon::Pokemon,
+};
+
+fn charizard() -> Pokemon {
+    serde_json::from_value(charizard_json()).unwrap()
+}
+
+fn charizard_json() -> serde_json::Value {
+    let stat = |name: &str, base: u32| {
+        serde_json::json!({ "base_stat": base, "effort": 0, "stat": { "name": name, "url": "" } })
+    };
+    serde_json::json!({
+        "id": 6,
+        "name": "charizard",
+        "base_experience": 267,
+        "height": 17,
+        "weight": 905,
+        "is_default": true,
+        "order": 7,
+        "abilities": [
+            { "is_hidden": false, "slot": 1, "ability": { "name": "blaze", "url": "" } },
+            { "is_hidden": true, "slot": 3, "ability": { "name": "solar-power", "url": "" } }
+        ],
+        "forms": [],
+        "game_indices": [],
+        "held_items": [],
+        "location_area_encounters": "",
+        "moves": [],
+        "species": { "name": "charizard", "url": "" },
+        "stats": [
+            stat("hp", 78), stat("attack", 84), stat("defense", 78),
+            stat("special-attack", 109), stat("special-defense", 85), stat("speed", 100)
+        ],
+        "types": [
+            { "slot": 2, "type": { "name": "flying", "url": "" } },
+            { "slot": 1, "type": { "name": "fire", "url": "" } }
+        ],
+        "sprites": {}
+    })
+}
+
+#[test]
+fn stats_are_summed_and_looked_up_by_name() {
+    let p = charizard();
+
+    assert_eq!(p.base_stat_total(), 534);
+    assert_eq!(p.stat(StatName::SpecialAttack), Some(109));
+    assert_eq!(p.stat(StatName::Accuracy), None);
+}
+
+#[test]
+fn types_follow_slots_not_array_order() {
+    let mut p = charizard();
+
+    assert_eq!(p.primary_type(), Some(TypeName::Fire));
+    assert_eq!(p.secondary_type(), Some(TypeName::Flying));
+
+    p.types.retain(|t| t.slot == 1);
+    assert_eq!(p.secondary_type(), None);
+}
+
+#[test]
+fn hidden_ability() {
+    let mut p = charizard();
+
+    assert_eq!(p.hidden_ability().map(|a| a.name.as_str()), Some("solar-power"));
+
+    p.abilities.retain(|a| !a.is_hidden);
+    assert_eq!(p.hidden_ability(), None);
+}
+
+#[test]
+fn height_and_weight_convert_from_api_units() {
+    let p = charizard();
+
+    assert_eq!(p.height_units().decimetres(), 17);
+    assert_eq!(p.height_units().centimetres(), 170);
+    assert_eq!(p.height_units().metres(), 1.7);
+    assert_eq!(p.height_units().to_string(), "1.7 m");
+
+    assert_eq!(p.weight_units().hectograms(), 905);
+    assert_eq!(p.weight_units().grams(), 90_500);
+    assert_eq!(p.weight_units().kilograms(), 90.5);
+    assert_eq!(p.weight_units().to_string(), "90.5 kg");
+}
+
+/// Charizard's payload with some fields replaced.
+fn with_history(overrides: serde_json::Value) -> Pokemon {
+    let mut json = charizard_json();
+    for (k, v) in overrides.as_object().unwrap() {
+        json[k] = v.clone();
+    }
+    serde_json::from_value(json).unwrap()
+}
+
+fn clefairy() -> Pokemon {
+    with_history(
+        serde_json::json!({
+            "types": [{ "slot": 1, "type": { "name": "fairy", "url": "" } }],
+            "past_types": [{
+                "generation": { "name": "generation-v", "url": "https://pokeapi.co/api/v2/generation/5/" },
+                "types": [{ "slot": 1, "type": { "name": "normal", "url": "" } }]
+            }]
+        }),
+    )
+}
+
+fn gengar() -> Pokemon {
+    with_history(
+        serde_json::json!({
+            "abilities": [
+                { "is_hidden": false, "slot": 1, "ability": { "name": "cursed-body", "url": "" } }
+            ],
+            "past_abilities": [
+                {
+                    "generation": { "name": "generation-vi", "url": "https://pokeapi.co/api/v2/generation/6/" },
+                    "abilities": [{ "is_hidden": false, "slot": 1, "ability": { "name": "levitate", "url": "" } }]
+                },
+                {
+                    "generation": { "name": "generation-iv", "url": "" },
+                    "abilities": [{ "is_hidden": true, "slot": 3, "ability": null }]
+                }
+            ]
+        }),
+    )
+}
+
+#[test]
+fn types_in_reconstructs_historical_typing() {
+    let p = clefairy();
+    let names = |gen: u32| p.types_in(gen).iter().map(|t| t.type_name()).collect::<Vec<_>>();
+
+    assert_eq!(names(1), [TypeName::Normal]);
+    assert_eq!(names(5), [TypeName::Normal]);
+    assert_eq!(names(6), [TypeName::Fairy]);
+    assert_eq!(names(9), [TypeName::Fairy]);
+
+    let gen_vi: krabdex::models::generation::Generation = serde_json::from_value(serde_json::json!({
+        "id": 6, "name": "generation-vi", "abilities": [], "moves": [], "pokemon_species": [],
+        "types": [], "version_groups": [], "main_region": { "name": "kalos", "url": "" }, "names": []
+    }))
+    .unwrap();
+    assert_eq!(p.types_in(&gen_vi)[0].type_name(), TypeName::Fairy);
+}
+
+#[test]
+fn abilities_in_merges_changed_slots() {
+    let p = gengar();
+    let names = |gen: u32| {
+        p.abilities_in(gen)
+            .into_iter()
+            .map(|a| a.ability.unwrap().name)
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(names(3), ["levitate"]);
+    assert_eq!(names(6), ["levitate"]);
+    assert_eq!(names(7), ["cursed-body"]);
+}
+
+#[test]
+fn generation_numbers_come_from_urls_or_roman_numerals() {
+    use krabdex::models::{common::NamedApiResource, generation::GenerationNumber};
+
+    let link = |name: &str, url: &str| NamedApiResource { name: name.into(), url: url.into() };
+
+    assert_eq!(GenerationNumber::of(&link("generation-iv", "")), Some(GenerationNumber(4)));
+    assert_eq!(GenerationNumber::of(&link("generation-ix", "")), Some(GenerationNumber(9)));
+    assert_eq!(
+        GenerationNumber::of(&link("x", "https://pokeapi.co/api/v2/generation/8/")),
+        Some(GenerationNumber(8))
+    );
+    assert_eq!(GenerationNumber::of(&link("generation-z", "")), None);
+}
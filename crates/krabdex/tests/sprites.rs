@@ -0,0 +1,120 @@
+use krabdex::models::sprites::{PokemonSprites, SpriteKind};
+use pretty_assertions::assert_eq;
+
+const BASE: &str = "https://raw.githubusercontent.com/PokeAPI/sprites/master/sprites/pokemon";
+
+fn pikachu_sprites() -> PokemonSprites {
+    serde_json::from_value(serde_json::json!({
+        "back_default": format!("{BASE}/back/25.png"),
+        "back_female": format!("{BASE}/back/female/25.png"),
+        "back_shiny": format!("{BASE}/back/shiny/25.png"),
+        "back_shiny_female": null,
+        "front_default": format!("{BASE}/25.png"),
+        "front_female": format!("{BASE}/female/25.png"),
+        "front_shiny": format!("{BASE}/shiny/25.png"),
+        "front_shiny_female": null,
+        "other": {
+            "dream_world": { "front_default": format!("{BASE}/other/dream-world/25.svg"), "front_female": null },
+            "home": {
+                "front_default": format!("{BASE}/other/home/25.png"),
+                "front_female": null,
+                "front_shiny": format!("{BASE}/other/home/shiny/25.png"),
+                "front_shiny_female": null
+            },
+            "official-artwork": {
+                "front_default": format!("{BASE}/other/official-artwork/25.png"),
+                "front_shiny": null
+            },
+            "showdown": {
+                "back_default": null, "back_female": null, "back_shiny": null, "back_shiny_female": null,
+                "front_default": null, "front_female": null, "front_shiny": null, "front_shiny_female": null
+            }
+        },
+        "versions": {
+            "generation-i": {
+                "red-blue": {
+                    "back_default": null, "back_gray": null, "back_transparent": null,
+                    "front_default": format!("{BASE}/versions/generation-i/red-blue/25.png"),
+                    "front_gray": null, "front_transparent": null
+                }
+            },
+            "generation-v": {
+                "black-white": {
+                    "animated": { "front_default": format!("{BASE}/versions/generation-v/black-white/animated/25.gif") },
+                    "front_default": format!("{BASE}/versions/generation-v/black-white/25.png")
+                }
+            }
+        }
+    }))
+    .unwrap()
+}
+
+#[test]
+fn sprites_deserialize_into_typed_model() {
+    let sprites = pikachu_sprites();
+
+    assert_eq!(sprites.front_default.as_deref(), Some(&*format!("{BASE}/25.png")));
+    assert_eq!(sprites.front_shiny_female, None);
+    assert_eq!(
+        sprites.other.official_artwork.front_default.as_deref(),
+        Some(&*format!("{BASE}/other/official-artwork/25.png"))
+    );
+
+    let red_blue = sprites.version("generation-i", "red-blue").unwrap();
+    assert!(red_blue.front_default.is_some());
+    assert_eq!(red_blue.front_gray, None);
+
+    let black_white = sprites.version("generation-v", "black-white").unwrap();
+    assert!(black_white.animated.as_ref().unwrap().front_default.as_deref().unwrap().ends_with(".gif"));
+    assert!(sprites.version("generation-ix", "scarlet-violet").is_none());
+}
+
+#[test]
+fn empty_sprites_object_is_accepted() {
+    let sprites: PokemonSprites = serde_json::from_str("{}").unwrap();
+
+    assert_eq!(sprites, PokemonSprites::default());
+    assert_eq!(sprites.best(SpriteKind::OfficialArtwork, true), None);
+}
+
+#[test]
+fn best_returns_the_requested_kind_when_present() {
+    let sprites = pikachu_sprites();
+
+    assert_eq!(
+        sprites.best(SpriteKind::OfficialArtwork, false),
+        Some(&*format!("{BASE}/other/official-artwork/25.png"))
+    );
+    assert_eq!(sprites.best(SpriteKind::Back, true), Some(&*format!("{BASE}/back/shiny/25.png")));
+}
+
+#[test]
+fn best_keeps_shininess_before_falling_back_to_default() {
+    let sprites = pikachu_sprites();
+
+    // No shiny official artwork: the shiny HOME render is the next best shiny image.
+    assert_eq!(
+        sprites.best(SpriteKind::OfficialArtwork, true),
+        Some(&*format!("{BASE}/other/home/shiny/25.png"))
+    );
+
+    // No showdown images at all: fall back along the preference order.
+    assert_eq!(
+        sprites.best(SpriteKind::Showdown, false),
+        Some(&*format!("{BASE}/other/official-artwork/25.png"))
+    );
+
+    let only_default = PokemonSprites {
+        front_default: Some("front.png".into()),
+        ..Default::default()
+    };
+    assert_eq!(only_default.best(SpriteKind::DreamWorld, true), Some("front.png"));
+}
+
+#[test]
+fn sprites_serialize_with_upstream_field_names() {
+    let value = serde_json::to_value(pikachu_sprites()).unwrap();
+
+    assert!(value["other"]["official-artwork"]["front_default"].is_string());
+    assert!(value["versions"]["generation-i"]["red-blue"].get("back_gray").is_none());
+}
@@ -0,0 +1,121 @@
+use std::{
+    collections::BTreeMap,
+    sync::{Arc, Mutex},
+};
+
+use httpmock::prelude::*;
+use krabdex::PokeApiClient;
+use tracing::{
+    field::{Field, Visit},
+    span::{Attributes, Id, Record},
+    Event, Subscriber,
+};
+use tracing_subscriber::{layer::Context, prelude::*, registry::LookupSpan, Layer};
+use url::Url;
+
+type Fields = BTreeMap<String, String>;
+
+/// Collects the fields of closed spans and emitted events.
+#[derive(Clone, Default)]
+struct Capture {
+    spans: Arc<Mutex<Vec<(String, Fields)>>>,
+    events: Arc<Mutex<Vec<Fields>>>,
+}
+
+struct FieldVisitor<'a>(&'a mut Fields);
+
+impl Visit for FieldVisitor<'_> {
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        self.0.insert(field.name().to_string(), format!("{value:?}"));
+    }
+}
+
+impl<S: Subscriber + for<'a> LookupSpan<'a>> Layer<S> for Capture {
+    fn on_new_span(&self, attrs: &Attributes<'_>, id: &Id, ctx: Context<'_, S>) {
+        let mut fields = Fields::new();
+        attrs.record(&mut FieldVisitor(&mut fields));
+        ctx.span(id).unwrap().extensions_mut().insert(fields);
+    }
+
+    fn on_record(&self, id: &Id, values: &Record<'_>, ctx: Context<'_, S>) {
+        let span = ctx.span(id).unwrap();
+        let mut ext = span.extensions_mut();
+        values.record(&mut FieldVisitor(ext.get_mut::<Fields>().unwrap()));
+    }
+
+    fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+        let mut fields = Fields::new();
+        event.record(&mut FieldVisitor(&mut fields));
+        self.events.lock().unwrap().push(fields);
+    }
+
+    fn on_close(&self, id: Id, ctx: Context<'_, S>) {
+        let span = ctx.span(&id).unwrap();
+        let fields = span.extensions().get::<Fields>().cloned().unwrap_or_default();
+        self.spans.lock().unwrap().push((span.name().to_string(), fields));
+    }
+}
+
+fn client_with_base(server: &MockServer) -> PokeApiClient {
+    PokeApiClient::builder()
+        .base_url(Url::parse(&server.base_url()).unwrap())
+        .retry(krabdex::client::retry::RetryConfig {
+            max_retries: 1,
+            initial_backoff: std::time::Duration::from_millis(1),
+            max_backoff: std::time::Duration::from_millis(1),
+        })
+        .build()
+        .unwrap()
+}
+
+#[tokio::test]
+async fn request_span_records_outcome() {
+    let capture = Capture::default();
+    let _guard = tracing_subscriber::registry().with(capture.clone()).set_default();
+
+    let server = MockServer::start();
+    server.mock(|when, then| {
+        when.method(GET).path("/api/v2/generation");
+        then.status(200).body(r#"{"count": 0, "next": null, "previous": null, "results": []}"#);
+    });
+
+    client_with_base(&server)
+        .generation_list(krabdex::types::PageRequest::first_page(krabdex::types::Limit::DEFAULT))
+        .await
+        .unwrap();
+
+    let spans = capture.spans.lock().unwrap();
+    let (name, fields) = spans.iter().find(|(n, _)| n == "krabdex.request").unwrap();
+    assert_eq!(name, "krabdex.request");
+    assert_eq!(fields["method"], "\"GET\"");
+    assert_eq!(fields["path"], "\"generation\"");
+    assert_eq!(fields["status"], "200");
+    assert_eq!(fields["attempt"], "1");
+    assert_eq!(fields["cache"], "Fetched");
+    assert!(fields.contains_key("latency_ms"));
+}
+
+#[tokio::test]
+async fn retries_and_deserialize_failures_emit_events() {
+    let capture = Capture::default();
+    let _guard = tracing_subscriber::registry().with(capture.clone()).set_default();
+
+    let server = MockServer::start();
+    server.mock(|when, then| {
+        when.method(GET).path("/api/v2/pokemon/1");
+        then.status(503);
+    });
+    server.mock(|when, then| {
+        when.method(GET).path("/api/v2/pokemon/2");
+        then.status(200).body("{}");
+    });
+
+    let client = client_with_base(&server);
+    client.pokemon_by_id(1).await.unwrap_err();
+    client.pokemon_by_id(2).await.unwrap_err();
+
+    let events = capture.events.lock().unwrap();
+    let messages: Vec<_> = events.iter().filter_map(|e| e.get("message").cloned()).collect();
+    assert!(messages.contains(&"retrying request".to_string()), "{messages:?}");
+    assert!(messages.contains(&"failed to deserialize response".to_string()), "{messages:?}");
+}
@@ -31,3 +31,115 @@ fn page_request_first_page_sets_offset_zero() {
     assert_eq!(pr.limit.get(), Limit::DEFAULT.get());
     assert_eq!(pr.offset.get(), 0);
 }
+
+#[test]
+fn owned_names_validate_and_round_trip() {
+    use krabdex::types::{GenerationNameBuf, PokemonNameBuf};
+
+    let name: PokemonNameBuf = "mr-mime".parse().unwrap();
+    assert_eq!(name.as_str(), "mr-mime");
+    assert_eq!(name.to_string(), "mr-mime");
+    assert_eq!(name.as_name(), PokemonName::new("mr-mime").unwrap());
+    assert_eq!(PokemonName::new("mr-mime").unwrap().to_buf(), name);
+
+    assert!(matches!(
+        "Mr. Mime".parse::<PokemonNameBuf>(),
+        Err(krabdex::Error::InvalidArgument { field: "pokemon_name", .. })
+    ));
+    assert!(matches!(
+        GenerationNameBuf::new(String::new()),
+        Err(krabdex::Error::InvalidArgument { field: "generation_name", .. })
+    ));
+}
+
+#[test]
+fn owned_names_serialize_as_strings_and_validate_on_deserialize() {
+    use krabdex::types::PokemonNameBuf;
+
+    #[derive(serde::Deserialize)]
+    struct Config {
+        favourites: Vec<PokemonNameBuf>,
+    }
+
+    let config: Config = serde_json::from_str(r#"{"favourites": ["pikachu", "eevee"]}"#).unwrap();
+    assert_eq!(serde_json::to_string(&config.favourites).unwrap(), r#"["pikachu","eevee"]"#);
+
+    let err = serde_json::from_str::<Config>(r#"{"favourites": ["Pikachu"]}"#).err().unwrap();
+    assert!(err.to_string().contains("lowercase"), "{err}");
+}
+
+#[test]
+fn owned_names_are_ordered_and_hashable() {
+    use std::collections::{BTreeSet, HashSet};
+    use krabdex::types::PokemonNameBuf;
+
+    let names = ["pikachu", "eevee", "pikachu"].map(|n| PokemonNameBuf::new(n).unwrap());
+
+    let sorted: Vec<&str> = names.iter().collect::<BTreeSet<_>>().into_iter().map(|n| n.as_str()).collect();
+    assert_eq!(sorted, ["eevee", "pikachu"]);
+
+    let set: HashSet<PokemonNameBuf> = names.into_iter().collect();
+    assert!(set.contains("eevee"));
+}
+
+#[test]
+fn normalize_maps_user_input_to_slugs() {
+    use krabdex::types::NameChange;
+
+    let cases = [
+        ("Mr. Mime", "mr-mime"),
+        ("Farfetch’d", "farfetchd"),
+        ("Nidoran♀", "nidoran-f"),
+        ("nidoran ♂", "nidoran-m"),
+        ("Pikachu", "pikachu"),
+        ("  Flabébé ", "flabebe"),
+        ("Type: Null", "type-null"),
+        ("mime_jr.", "mime-jr"),
+        ("Mr.Mime", "mr-mime"),
+        ("Type:Null", "type-null"),
+        ("Mime.Jr", "mime-jr"),
+        ("farfetch'd", "farfetchd"),
+    ];
+    for (input, slug) in cases {
+        assert_eq!(PokemonName::normalize(input).unwrap().name.as_str(), slug, "{input}");
+    }
+
+    let n = PokemonName::normalize("Mr. Mime").unwrap();
+    assert_eq!(n.original, "Mr. Mime");
+    assert_eq!(
+        n.changes,
+        [NameChange::Lowercased, NameChange::PunctuationRemoved, NameChange::SeparatorsReplaced]
+    );
+
+    let n = PokemonName::normalize("  Flabébé ").unwrap();
+    assert_eq!(
+        n.changes,
+        [NameChange::Trimmed, NameChange::Lowercased, NameChange::DiacriticsRemoved]
+    );
+}
+
+#[test]
+fn normalize_leaves_valid_slugs_alone_and_rejects_leftovers() {
+    let n = PokemonName::normalize("ho-oh").unwrap();
+    assert!(n.is_unchanged());
+    assert_eq!(n.name.as_name(), PokemonName::new("ho-oh").unwrap());
+
+    assert!(matches!(
+        PokemonName::normalize("?!"),
+        Err(krabdex::Error::InvalidArgument { field: "pokemon_name", .. })
+    ));
+}
+
+#[test]
+fn normalize_rejects_characters_it_cannot_map() {
+    for input in ["ピカチュウ", "Pikachu皮", "straße", "æon", "Пикачу"] {
+        let err = PokemonName::normalize(input).unwrap_err();
+        assert!(
+            matches!(&err, krabdex::Error::InvalidArgument { field: "pokemon_name", reason } if reason.starts_with("cannot normalize character")),
+            "{input}: {err}"
+        );
+    }
+
+    let err = PokemonName::normalize("straße").unwrap_err();
+    assert_eq!(err.to_string(), "invalid argument `pokemon_name`: cannot normalize character `ß`");
+}